@@ -0,0 +1,220 @@
+//! Decodes the machine-readable test results emitted by the boards.
+//!
+//! See the `shared::results` module for the protocol itself. Result records may be mixed with
+//! any other serial output, which is skipped.
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+pub const PREFIX: &str = "@atr";
+pub const PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+impl Outcome {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Skip => "skip",
+        }
+    }
+}
+impl FromStr for Outcome {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pass" => Ok(Self::Pass),
+            "fail" => Ok(Self::Fail),
+            "skip" => Ok(Self::Skip),
+            _ => Err(DecodeError::InvalidOutcome(s.into())),
+        }
+    }
+}
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single decoded result record.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Record {
+    Hello {
+        version: u32,
+    },
+    Test {
+        name: String,
+    },
+    Case {
+        name: String,
+        outcome: Outcome,
+        message: Option<String>,
+    },
+    Value {
+        name: String,
+        value: String,
+    },
+    Panic {
+        message: String,
+    },
+    /// A panic from before the last reset, the `count`th since power on.
+    Fault {
+        count: u32,
+        message: String,
+    },
+    End,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum DecodeError {
+    #[error("unknown record kind `{0}`")]
+    UnknownKind(String),
+    #[error("the `{0}` record has the wrong number of fields")]
+    WrongFieldCount(&'static str),
+    #[error("invalid escape sequence in `{0}`")]
+    InvalidEscape(String),
+    #[error("invalid outcome `{0}`")]
+    InvalidOutcome(String),
+    #[error("invalid protocol version `{0}`")]
+    InvalidVersion(String),
+    #[error("invalid fault count `{0}`")]
+    InvalidCount(String),
+}
+
+/// Reverses the escaping of a field.
+fn unescape(field: &str) -> Result<String, DecodeError> {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        out.push(match chars.next() {
+            Some('\\') => '\\',
+            Some('s') => ' ',
+            Some('r') => '\r',
+            Some('n') => '\n',
+            _ => return Err(DecodeError::InvalidEscape(field.into())),
+        });
+    }
+
+    Ok(out)
+}
+
+/// Decodes a single line of serial output.
+///
+/// Returns `None` if the line is not a result record at all, with any line ending and leading
+/// whitespace being ignored.
+pub fn decode_line(line: &str) -> Option<Result<Record, DecodeError>> {
+    let line = line.trim_start().trim_end_matches(['\r', '\n']);
+    let mut parts = line.split(' ');
+    if parts.next() != Some(PREFIX) {
+        return None;
+    }
+    let kind = parts.next().unwrap_or_default();
+
+    Some(decode_fields(kind, parts))
+}
+
+fn decode_fields<'a>(
+    kind: &str,
+    fields: impl Iterator<Item = &'a str>,
+) -> Result<Record, DecodeError> {
+    let fields = fields.map(unescape).collect::<Result<Vec<_>, _>>()?;
+
+    Ok(match kind {
+        "hello" => {
+            let [version] = <[String; 1]>::try_from(fields)
+                .map_err(|_| DecodeError::WrongFieldCount("hello"))?;
+            Record::Hello {
+                version: version
+                    .parse()
+                    .map_err(|_| DecodeError::InvalidVersion(version))?,
+            }
+        }
+        "test" => {
+            let [name] = <[String; 1]>::try_from(fields)
+                .map_err(|_| DecodeError::WrongFieldCount("test"))?;
+            Record::Test { name }
+        }
+        "case" => {
+            let mut fields = fields.into_iter();
+            let (Some(name), Some(outcome), message, None) =
+                (fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                return Err(DecodeError::WrongFieldCount("case"));
+            };
+            Record::Case {
+                name,
+                outcome: outcome.parse()?,
+                message,
+            }
+        }
+        "value" => {
+            let [name, value] = <[String; 2]>::try_from(fields)
+                .map_err(|_| DecodeError::WrongFieldCount("value"))?;
+            Record::Value { name, value }
+        }
+        "panic" => {
+            let [message] = <[String; 1]>::try_from(fields)
+                .map_err(|_| DecodeError::WrongFieldCount("panic"))?;
+            Record::Panic { message }
+        }
+        "fault" => {
+            let [count, message] = <[String; 2]>::try_from(fields)
+                .map_err(|_| DecodeError::WrongFieldCount("fault"))?;
+            Record::Fault {
+                count: count
+                    .parse()
+                    .map_err(|_| DecodeError::InvalidCount(count))?,
+                message,
+            }
+        }
+        "end" => {
+            if !fields.is_empty() {
+                return Err(DecodeError::WrongFieldCount("end"));
+            }
+            Record::End
+        }
+        _ => return Err(DecodeError::UnknownKind(kind.into())),
+    })
+}
+
+/// Decodes records from a stream of serial output that arrives in arbitrary chunks.
+#[derive(Default)]
+pub struct Decoder {
+    partial: Vec<u8>,
+}
+impl Decoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds received bytes, returning the records of any lines that were completed.
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<Result<Record, DecodeError>> {
+        self.partial.extend_from_slice(bytes);
+
+        let mut records = Vec::new();
+        while let Some(end) = self.partial.iter().position(|b| *b == b'\n') {
+            let line: Vec<_> = self.partial.drain(..=end).collect();
+            records.extend(decode_line(&String::from_utf8_lossy(&line)));
+        }
+
+        records
+    }
+
+    /// Decodes whatever is left over once the stream has ended, which may be a record whose
+    /// line ending never arrived.
+    pub fn finish(mut self) -> Option<Result<Record, DecodeError>> {
+        let line = std::mem::take(&mut self.partial);
+        decode_line(&String::from_utf8_lossy(&line))
+    }
+}
@@ -0,0 +1,188 @@
+//! Round trips records through the device-side encoder in `shared` and back.
+use results_decoder::{DecodeError, Decoder, Outcome, Record, decode_line};
+use shared::results::{self, ResultWriter};
+
+/// Encodes records with the device-side encoder.
+fn encode(f: impl FnOnce(&mut ResultWriter<&mut String>) -> core::fmt::Result) -> String {
+    let mut out = String::new();
+    f(&mut ResultWriter::new(&mut out)).unwrap();
+    out
+}
+
+fn decode_all(text: &str) -> Vec<Record> {
+    let mut decoder = Decoder::new();
+    let records = decoder.push(text.as_bytes());
+    assert_eq!(decoder.finish(), None);
+    records.into_iter().map(Result::unwrap).collect()
+}
+
+fn case(name: &str, outcome: Outcome, message: Option<&str>) -> Record {
+    Record::Case {
+        name: name.into(),
+        outcome,
+        message: message.map(Into::into),
+    }
+}
+
+#[test]
+fn constants_match() {
+    assert_eq!(results::PREFIX, results_decoder::PREFIX);
+    assert_eq!(results::PROTOCOL_VERSION, results_decoder::PROTOCOL_VERSION);
+    for (device, host) in [
+        (results::Outcome::Pass, Outcome::Pass),
+        (results::Outcome::Fail, Outcome::Fail),
+        (results::Outcome::Skip, Outcome::Skip),
+    ] {
+        assert_eq!(device.as_str(), host.as_str());
+    }
+}
+
+#[test]
+fn all_records() {
+    let text = encode(|w| {
+        w.hello()?;
+        w.test("rtc")?;
+        w.case("count32", results::Outcome::Pass, None)?;
+        w.value("ticks", &1024)?;
+        w.case(
+            "clock",
+            results::Outcome::Fail,
+            Some(&"expected 2 but got 3"),
+        )?;
+        w.case("alarm", results::Outcome::Skip, Some(&"not supported"))?;
+        w.panic(&"oops")?;
+        w.fault(2, &"panicked at src/main.rs:1:1:\noops")?;
+        w.end()
+    });
+
+    assert_eq!(
+        decode_all(&text),
+        [
+            Record::Hello {
+                version: results::PROTOCOL_VERSION
+            },
+            Record::Test { name: "rtc".into() },
+            case("count32", Outcome::Pass, None),
+            Record::Value {
+                name: "ticks".into(),
+                value: "1024".into()
+            },
+            case("clock", Outcome::Fail, Some("expected 2 but got 3")),
+            case("alarm", Outcome::Skip, Some("not supported")),
+            Record::Panic {
+                message: "oops".into()
+            },
+            Record::Fault {
+                count: 2,
+                message: "panicked at src/main.rs:1:1:\noops".into()
+            },
+            Record::End,
+        ]
+    );
+}
+
+#[test]
+fn escaping() {
+    let awkward = "a b\\s\r\nc\\\\ d  ";
+    let text = encode(|w| {
+        w.test(awkward)?;
+        w.case(awkward, results::Outcome::Fail, Some(&awkward))?;
+        w.panic(&format_args!("panicked at src/main.rs:1:1:\n{awkward}"))
+    });
+
+    // Everything is escaped, so there is one line per record
+    assert_eq!(text.lines().count(), 3);
+    assert_eq!(
+        decode_all(&text),
+        [
+            Record::Test {
+                name: awkward.into()
+            },
+            case(awkward, Outcome::Fail, Some(awkward)),
+            Record::Panic {
+                message: format!("panicked at src/main.rs:1:1:\n{awkward}")
+            },
+        ]
+    );
+}
+
+#[test]
+fn empty_fields() {
+    let text = encode(|w| w.case("", results::Outcome::Pass, Some(&"")));
+
+    assert_eq!(decode_all(&text), [case("", Outcome::Pass, Some(""))]);
+}
+
+#[test]
+fn mixed_with_other_output() {
+    let records = encode(|w| {
+        w.test("delay_ns")?;
+        w.end()
+    });
+    let text = format!("Booting...\r\n{records}Loop iteration number 3\r\n  @atrium\r\n");
+
+    assert_eq!(
+        decode_all(&text),
+        [
+            Record::Test {
+                name: "delay_ns".into()
+            },
+            Record::End
+        ]
+    );
+}
+
+#[test]
+fn split_into_chunks() {
+    let text = encode(|w| {
+        w.hello()?;
+        w.test("rtc")?;
+        w.value("drift", &format_args!("{:.3}", 1.25))?;
+        w.end()
+    });
+    let expected = decode_all(&text);
+
+    // Every chunk size, including one byte at a time
+    for size in 1..text.len() {
+        let mut decoder = Decoder::new();
+        let records: Vec<_> = text
+            .as_bytes()
+            .chunks(size)
+            .flat_map(|chunk| decoder.push(chunk))
+            .map(Result::unwrap)
+            .collect();
+
+        assert_eq!(records, expected, "chunk size {size}");
+        assert_eq!(decoder.finish(), None);
+    }
+}
+
+#[test]
+fn unterminated_record() {
+    let mut decoder = Decoder::new();
+
+    assert!(decoder.push(b"@atr end").is_empty());
+    assert_eq!(decoder.finish(), Some(Ok(Record::End)));
+}
+
+#[test]
+fn malformed_records() {
+    for (line, error) in [
+        ("@atr bogus", DecodeError::UnknownKind("bogus".into())),
+        ("@atr test", DecodeError::WrongFieldCount("test")),
+        ("@atr test a b", DecodeError::WrongFieldCount("test")),
+        ("@atr case a pass b c", DecodeError::WrongFieldCount("case")),
+        ("@atr end now", DecodeError::WrongFieldCount("end")),
+        (
+            "@atr case a maybe",
+            DecodeError::InvalidOutcome("maybe".into()),
+        ),
+        ("@atr hello one", DecodeError::InvalidVersion("one".into())),
+        ("@atr fault -1 oops", DecodeError::InvalidCount("-1".into())),
+        ("@atr fault 1", DecodeError::WrongFieldCount("fault")),
+        ("@atr test a\\t", DecodeError::InvalidEscape("a\\t".into())),
+        ("@atr test a\\", DecodeError::InvalidEscape("a\\".into())),
+    ] {
+        assert_eq!(decode_line(line), Some(Err(error)), "{line}");
+    }
+}
@@ -0,0 +1,154 @@
+//! Writes a [`Report`] as JUnit XML, with each test as a test suite of its cases.
+use crate::report::{Report, Status, TestRun, Value};
+use quick_xml::{
+    Writer,
+    escape::escape,
+    events::{BytesDecl, Event, attributes::Attribute},
+    name::QName,
+};
+use results_decoder::Outcome;
+use std::io::{self, Write};
+
+pub fn write_junit(report: &Report, output: impl Write) -> io::Result<()> {
+    let mut writer = Writer::new_with_indent(output, b' ', 2);
+    writer.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+
+    writer
+        .create_element("testsuites")
+        .with_attributes([
+            ("name", "atsamd-tests"),
+            ("tests", total(report, num_cases).as_str()),
+            (
+                "failures",
+                total(report, |t| t.count(Outcome::Fail)).as_str(),
+            ),
+            ("errors", report.count(Status::Errored).to_string().as_str()),
+            (
+                "skipped",
+                total(report, |t| t.count(Outcome::Skip)).as_str(),
+            ),
+        ])
+        .write_inner_content(|w| {
+            for test in &report.tests {
+                write_suite(w, test)?;
+            }
+            Ok(())
+        })?;
+
+    writer.into_inner().write_all(b"\n")
+}
+
+fn total(report: &Report, f: impl Fn(&TestRun) -> usize) -> String {
+    report.tests.iter().map(f).sum::<usize>().to_string()
+}
+
+/// Number of test cases in the suite, including the one for an error.
+fn num_cases(test: &TestRun) -> usize {
+    test.cases.len() + usize::from(test.error.is_some() || test.cases.is_empty())
+}
+
+/// Messages often span multiple lines, and line breaks would otherwise become spaces when the
+/// attribute is read back.
+fn message_attribute(message: &str) -> Attribute<'static> {
+    let value = escape(message)
+        .replace('\r', "&#13;")
+        .replace('\n', "&#10;");
+
+    Attribute {
+        key: QName(b"message"),
+        value: value.into_bytes().into(),
+    }
+}
+
+fn write_suite<W: Write>(writer: &mut Writer<W>, test: &TestRun) -> io::Result<()> {
+    let name = test.display_name();
+
+    writer
+        .create_element("testsuite")
+        .with_attributes([
+            ("name", name),
+            ("tests", num_cases(test).to_string().as_str()),
+            ("failures", test.count(Outcome::Fail).to_string().as_str()),
+            (
+                "errors",
+                usize::from(test.error.is_some()).to_string().as_str(),
+            ),
+            ("skipped", test.count(Outcome::Skip).to_string().as_str()),
+        ])
+        .write_inner_content(|w| {
+            write_properties(w, &test.values)?;
+
+            for case in &test.cases {
+                let element = w
+                    .create_element("testcase")
+                    .with_attributes([("name", case.name.as_str()), ("classname", name)]);
+                let message = case.message.as_deref().unwrap_or_default();
+
+                match case.outcome {
+                    Outcome::Pass if case.values.is_empty() => {
+                        element.write_empty()?;
+                    }
+                    outcome => {
+                        element.write_inner_content(|w| {
+                            match outcome {
+                                Outcome::Pass => {}
+                                Outcome::Fail => {
+                                    w.create_element("failure")
+                                        .with_attribute(message_attribute(message))
+                                        .write_empty()?;
+                                }
+                                Outcome::Skip => {
+                                    w.create_element("skipped")
+                                        .with_attribute(message_attribute(message))
+                                        .write_empty()?;
+                                }
+                            }
+                            write_properties(w, &case.values)
+                        })?;
+                    }
+                }
+            }
+
+            // Errors and tests without cases still need a test case to show up at all
+            if let Some(error) = &test.error {
+                w.create_element("testcase")
+                    .with_attributes([("name", name), ("classname", name)])
+                    .write_inner_content(|w| {
+                        w.create_element("error")
+                            .with_attribute(message_attribute(error))
+                            .write_empty()?;
+                        Ok(())
+                    })?;
+            } else if test.cases.is_empty() {
+                w.create_element("testcase")
+                    .with_attributes([("name", name), ("classname", name)])
+                    .write_empty()?;
+            }
+
+            Ok(())
+        })?;
+
+    Ok(())
+}
+
+fn write_properties<W: Write>(writer: &mut Writer<W>, values: &[Value]) -> io::Result<()> {
+    if values.is_empty() {
+        return Ok(());
+    }
+
+    writer
+        .create_element("properties")
+        .write_inner_content(|w| {
+            for value in values {
+                w.create_element("property")
+                    .with_attributes([
+                        ("name", value.name.as_str()),
+                        ("value", value.value.as_str()),
+                    ])
+                    .write_empty()?;
+            }
+            Ok(())
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,8 @@
+//! Collects the machine-readable results of the test programs into reports.
+pub mod junit;
+pub mod report;
+pub mod summary;
+
+pub use junit::write_junit;
+pub use report::{Collector, Report, Status};
+pub use summary::write_summary;
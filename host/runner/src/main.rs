@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use atsamd_tests_runner::{Collector, Report, write_junit, write_summary};
+use clap::{Args, Parser};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+/// Collects the results of the test programs from a board or a recorded transcript of its serial
+/// output.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(flatten)]
+    source: Source,
+    /// Baud rate of the serial port, which USB serial devices ignore.
+    #[arg(long, default_value_t = 115_200)]
+    baud: u32,
+    /// Also record the raw serial output to this file, so it can be replayed with `--log`.
+    #[arg(long, requires = "port")]
+    capture: Option<PathBuf>,
+    /// Stop reading the serial port once this many tests have finished.
+    #[arg(long)]
+    tests: Option<usize>,
+    /// Stop reading the serial port after this many seconds without any output.
+    #[arg(long, default_value_t = 60)]
+    idle_timeout: u64,
+    /// Write a JUnit XML report to this file.
+    #[arg(long)]
+    junit: Option<PathBuf>,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct Source {
+    /// Serial port of the board to read results from.
+    #[arg(long)]
+    port: Option<String>,
+    /// Recorded serial output to replay instead of reading from a board.
+    #[arg(long)]
+    log: Option<PathBuf>,
+}
+
+fn read_port(cli: &Cli, path: &str) -> Result<Report> {
+    let mut port = serialport::new(path, cli.baud)
+        .timeout(Duration::from_millis(100))
+        .open()
+        .with_context(|| format!("Could not open serial port `{path}`"))?;
+    // Some USB serial devices only send once the terminal is ready
+    port.write_data_terminal_ready(true).ok();
+
+    let mut capture = cli
+        .capture
+        .as_ref()
+        .map(|p| {
+            File::create(p)
+                .map(BufWriter::new)
+                .with_context(|| format!("Could not create `{}`", p.display()))
+        })
+        .transpose()?;
+    let idle_timeout = Duration::from_secs(cli.idle_timeout);
+    let mut collector = Collector::new();
+    let mut last_output = Instant::now();
+    let mut buf = [0; 1024];
+
+    loop {
+        match port.read(&mut buf) {
+            Ok(count) => {
+                last_output = Instant::now();
+                if let Some(capture) = capture.as_mut() {
+                    capture.write_all(&buf[..count])?;
+                }
+                for test in collector.push(&buf[..count]) {
+                    eprintln!("{}: {}", test.display_name(), test.status().as_str());
+                }
+                if cli.tests.is_some_and(|n| collector.finished() >= n) {
+                    break;
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => return Err(e).context("Could not read from the serial port"),
+        }
+
+        if last_output.elapsed() >= idle_timeout {
+            eprintln!("No output for {} seconds, stopping", cli.idle_timeout);
+            break;
+        }
+    }
+
+    if let Some(mut capture) = capture {
+        capture.flush()?;
+    }
+    Ok(collector.finish())
+}
+
+fn run(cli: &Cli) -> Result<bool> {
+    let report = match (&cli.source.port, &cli.source.log) {
+        (Some(port), _) => read_port(cli, port)?,
+        (_, Some(log)) => Report::from_transcript(
+            File::open(log).with_context(|| format!("Could not open `{}`", log.display()))?,
+        )?,
+        (None, None) => unreachable!("clap requires a source"),
+    };
+
+    if let Some(path) = &cli.junit {
+        let mut file = BufWriter::new(
+            File::create(path).with_context(|| format!("Could not create `{}`", path.display()))?,
+        );
+        write_junit(&report, &mut file)?;
+        file.flush()?;
+    }
+    write_summary(&report, io::stdout().lock())?;
+
+    Ok(report.success())
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+
+    Ok(if run(&cli)? {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
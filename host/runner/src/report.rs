@@ -0,0 +1,228 @@
+//! Collects decoded result records into per-test results.
+use results_decoder::{DecodeError, Decoder, Outcome, Record};
+use std::io::{self, Read};
+
+/// A named value measured by the device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Value {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CaseResult {
+    pub name: String,
+    pub outcome: Outcome,
+    pub message: Option<String>,
+    pub values: Vec<Value>,
+}
+
+/// Overall status of a test run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Passed,
+    Failed,
+    /// The test never finished, for example because the device panicked.
+    Errored,
+}
+impl Status {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Passed => "PASS",
+            Self::Failed => "FAIL",
+            Self::Errored => "ERROR",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TestRun {
+    pub name: String,
+    pub cases: Vec<CaseResult>,
+    /// Values measured before any case finished.
+    pub values: Vec<Value>,
+    /// Why the test did not finish, if it did not.
+    pub error: Option<String>,
+}
+impl TestRun {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            cases: Vec::new(),
+            values: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// The name to show, since records can also arrive outside of any test.
+    pub fn display_name(&self) -> &str {
+        if self.name.is_empty() {
+            "(unnamed)"
+        } else {
+            &self.name
+        }
+    }
+
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.cases.iter().filter(|c| c.outcome == outcome).count()
+    }
+
+    pub fn status(&self) -> Status {
+        if self.error.is_some() {
+            Status::Errored
+        } else if self.count(Outcome::Fail) > 0 {
+            Status::Failed
+        } else {
+            Status::Passed
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub tests: Vec<TestRun>,
+    /// Records that could not be decoded.
+    pub bad_records: Vec<DecodeError>,
+}
+impl Report {
+    /// Collects the results from a recorded transcript of the serial output.
+    pub fn from_transcript(mut reader: impl Read) -> io::Result<Self> {
+        let mut collector = Collector::new();
+        let mut buf = [0; 4096];
+
+        loop {
+            match reader.read(&mut buf)? {
+                0 => break,
+                count => {
+                    collector.push(&buf[..count]);
+                }
+            }
+        }
+
+        Ok(collector.finish())
+    }
+
+    pub fn count(&self, status: Status) -> usize {
+        self.tests.iter().filter(|t| t.status() == status).count()
+    }
+
+    /// Whether every test passed, with at least one test having been run.
+    pub fn success(&self) -> bool {
+        !self.tests.is_empty() && self.count(Status::Passed) == self.tests.len()
+    }
+}
+
+/// Builds a [`Report`] from serial output as it arrives.
+#[derive(Default)]
+pub struct Collector {
+    decoder: Decoder,
+    report: Report,
+    current: Option<TestRun>,
+    /// The test that the device restarted during, until the next record.
+    restarted: Option<usize>,
+}
+impl Collector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds received serial output, returning any tests that finished as a result.
+    pub fn push(&mut self, bytes: &[u8]) -> &[TestRun] {
+        let finished = self.report.tests.len();
+
+        for result in self.decoder.push(bytes) {
+            self.handle(result);
+        }
+
+        &self.report.tests[finished..]
+    }
+
+    /// Number of tests that have finished so far.
+    pub fn finished(&self) -> usize {
+        self.report.tests.len()
+    }
+
+    pub fn finish(mut self) -> Report {
+        let decoder = std::mem::take(&mut self.decoder);
+        if let Some(result) = decoder.finish() {
+            self.handle(result);
+        }
+        self.close("The transcript ended before the test did");
+
+        self.report
+    }
+
+    fn handle(&mut self, result: Result<Record, DecodeError>) {
+        let record = match result {
+            Ok(r) => r,
+            Err(e) => {
+                self.report.bad_records.push(e);
+                return;
+            }
+        };
+
+        let restarted = self.restarted.take();
+        match record {
+            Record::Hello { .. } => {
+                if self.current.is_some() {
+                    self.close("The device restarted before the test ended");
+                    self.restarted = Some(self.report.tests.len() - 1);
+                }
+            }
+            Record::Test { name } => {
+                self.close("Another test started before the test ended");
+                self.current = Some(TestRun::new(name));
+            }
+            Record::Case {
+                name,
+                outcome,
+                message,
+            } => self.current().cases.push(CaseResult {
+                name,
+                outcome,
+                message,
+                values: Vec::new(),
+            }),
+            Record::Value { name, value } => {
+                let test = self.current();
+                let value = Value { name, value };
+                match test.cases.last_mut() {
+                    Some(case) => case.values.push(value),
+                    None => test.values.push(value),
+                }
+            }
+            Record::Panic { message } => {
+                self.current().error = Some(format!("Panicked: {message}"));
+                self.end();
+            }
+            // The panic kept across the restart is only news if its own record did not get out
+            Record::Fault { count, message } => {
+                if let Some(idx) = restarted {
+                    self.report.tests[idx].error =
+                        Some(format!("Panicked (fault {count}): {message}"));
+                }
+            }
+            Record::End => self.end(),
+        }
+    }
+
+    /// The current test, with records outside of any test being collected into an unnamed one.
+    fn current(&mut self) -> &mut TestRun {
+        self.current
+            .get_or_insert_with(|| TestRun::new(String::new()))
+    }
+
+    fn end(&mut self) {
+        if let Some(test) = self.current.take() {
+            self.report.tests.push(test);
+        }
+    }
+
+    /// Ends the current test, if any, with an error.
+    fn close(&mut self, error: &str) {
+        if let Some(test) = self.current.as_mut() {
+            test.error.get_or_insert_with(|| error.into());
+            self.end();
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Writes a human-readable summary table of a [`Report`].
+use crate::report::{Report, Status};
+use results_decoder::Outcome;
+use std::io::{self, Write};
+
+const HEADINGS: [&str; 5] = ["Test", "Result", "Pass", "Fail", "Skip"];
+
+pub fn write_summary(report: &Report, mut output: impl Write) -> io::Result<()> {
+    let rows: Vec<[String; 5]> = report
+        .tests
+        .iter()
+        .map(|t| {
+            [
+                t.display_name().into(),
+                t.status().as_str().into(),
+                t.count(Outcome::Pass).to_string(),
+                t.count(Outcome::Fail).to_string(),
+                t.count(Outcome::Skip).to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = HEADINGS.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    // Names are left aligned and counts right aligned
+    let write_row = |output: &mut dyn Write, row: [&str; 5]| {
+        writeln!(
+            output,
+            "{:<w0$}  {:<w1$}  {:>w2$}  {:>w3$}  {:>w4$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+            w4 = widths[4],
+        )
+    };
+    write_row(&mut output, HEADINGS)?;
+    writeln!(
+        output,
+        "{}",
+        "-".repeat(widths.iter().sum::<usize>() + 2 * 4)
+    )?;
+    for row in &rows {
+        write_row(&mut output, row.each_ref().map(String::as_str))?;
+    }
+
+    // Details of everything that did not pass
+    let mut first = true;
+    for test in &report.tests {
+        let name = test.display_name();
+        let problems = test
+            .cases
+            .iter()
+            .filter(|c| c.outcome == Outcome::Fail)
+            .map(|c| (c.name.as_str(), c.message.as_deref().unwrap_or("Failed")))
+            .chain(test.error.as_deref().map(|e| ("", e)));
+
+        for (case, message) in problems {
+            if first {
+                writeln!(output)?;
+                first = false;
+            }
+            if case.is_empty() {
+                writeln!(output, "{name}: {message}")?;
+            } else {
+                writeln!(output, "{name}/{case}: {message}")?;
+            }
+        }
+    }
+
+    writeln!(
+        output,
+        "\n{} tests: {} passed, {} failed, {} errored",
+        report.tests.len(),
+        report.count(Status::Passed),
+        report.count(Status::Failed),
+        report.count(Status::Errored),
+    )?;
+    if !report.bad_records.is_empty() {
+        writeln!(
+            output,
+            "{} records could not be decoded",
+            report.bad_records.len()
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Replays recorded transcripts, so the runner can be checked without a board attached.
+use atsamd_tests_runner::{Report, Status, write_junit, write_summary};
+use std::{fs::File, path::PathBuf, process::Command};
+
+fn transcript(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("transcripts")
+        .join(name)
+}
+
+fn replay(name: &str) -> Report {
+    Report::from_transcript(File::open(transcript(name)).unwrap()).unwrap()
+}
+
+fn summary(report: &Report) -> String {
+    let mut out = Vec::new();
+    write_summary(report, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+fn junit(report: &Report) -> String {
+    let mut out = Vec::new();
+    write_junit(report, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn passing() {
+    let report = replay("passing.log");
+
+    assert!(report.success());
+    assert_eq!(
+        summary(&report),
+        "\
+Test      Result  Pass  Fail  Skip
+----------------------------------
+rtc       PASS       2     0     0
+delay_ns  PASS       2     0     0
+
+2 tests: 2 passed, 0 failed, 0 errored
+"
+    );
+}
+
+#[test]
+fn failing() {
+    let report = replay("failing.log");
+
+    assert!(!report.success());
+    let statuses: Vec<_> = report
+        .tests
+        .iter()
+        .map(|t| (t.name.as_str(), t.status()))
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ("rtc", Status::Failed),
+            ("delay_ns", Status::Errored),
+            ("dsu", Status::Errored),
+            ("dmac", Status::Errored),
+        ]
+    );
+    assert_eq!(report.bad_records.len(), 1);
+    assert_eq!(
+        summary(&report),
+        "\
+Test      Result  Pass  Fail  Skip
+----------------------------------
+rtc       FAIL       1     1     1
+delay_ns  ERROR      1     0     0
+dsu       ERROR      1     0     0
+dmac      ERROR      1     0     0
+
+rtc/count32: expected 1000 but got 0x3E7 (999)
+delay_ns: Panicked: panicked at src/main.rs:10:5:
+timeout
+dsu: The device restarted before the test ended
+dmac: The transcript ended before the test did
+
+4 tests: 0 passed, 1 failed, 3 errored
+1 records could not be decoded
+"
+    );
+}
+
+#[test]
+fn panic_only_kept_across_restart() {
+    // The panic record never got out, but the device kept the panic for after the restart
+    let transcript = "\
+@atr hello 1\r
+@atr test dsu\r
+@atr case crc pass\r
+@atr hello 1\r
+@atr fault 3 panicked\\sat\\ssrc/main.rs:20:9:\\nbusy\r
+";
+    let report = Report::from_transcript(transcript.as_bytes()).unwrap();
+
+    assert_eq!(report.tests.len(), 1);
+    assert_eq!(
+        report.tests[0].error.as_deref(),
+        Some("Panicked (fault 3): panicked at src/main.rs:20:9:\nbusy")
+    );
+    assert!(report.bad_records.is_empty());
+}
+
+#[test]
+fn failing_junit() {
+    let report = replay("failing.log");
+
+    assert_eq!(
+        junit(&report),
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites name="atsamd-tests" tests="9" failures="1" errors="3" skipped="1">
+  <testsuite name="rtc" tests="3" failures="1" errors="0" skipped="1">
+    <properties>
+      <property name="rate" value="1024"/>
+    </properties>
+    <testcase name="count32" classname="rtc">
+      <failure message="expected 1000 but got 0x3E7 (999)"/>
+    </testcase>
+    <testcase name="alarm" classname="rtc">
+      <skipped message="Not supported on SAMD21"/>
+    </testcase>
+    <testcase name="clock" classname="rtc"/>
+  </testsuite>
+  <testsuite name="delay_ns" tests="2" failures="0" errors="1" skipped="0">
+    <testcase name="Delay" classname="delay_ns"/>
+    <testcase name="delay_ns" classname="delay_ns">
+      <error message="Panicked: panicked at src/main.rs:10:5:&#10;timeout"/>
+    </testcase>
+  </testsuite>
+  <testsuite name="dsu" tests="2" failures="0" errors="1" skipped="0">
+    <testcase name="crc" classname="dsu"/>
+    <testcase name="dsu" classname="dsu">
+      <error message="The device restarted before the test ended"/>
+    </testcase>
+  </testsuite>
+  <testsuite name="dmac" tests="2" failures="0" errors="1" skipped="0">
+    <testcase name="transfer" classname="dmac"/>
+    <testcase name="dmac" classname="dmac">
+      <error message="The transcript ended before the test did"/>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+    );
+}
+
+#[test]
+fn cli_replay() {
+    let dir = tempfile::tempdir().unwrap();
+    let junit_path = dir.path().join("results.xml");
+    let run = |log: &str| {
+        Command::new(env!("CARGO_BIN_EXE_atsamd-tests-runner"))
+            .arg("--log")
+            .arg(transcript(log))
+            .arg("--junit")
+            .arg(&junit_path)
+            .output()
+            .unwrap()
+    };
+
+    let output = run("passing.log");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        summary(&replay("passing.log"))
+    );
+    assert_eq!(
+        std::fs::read_to_string(&junit_path).unwrap(),
+        junit(&replay("passing.log"))
+    );
+
+    // Failures are reported through the exit code
+    let output = run("failing.log");
+    assert!(!output.status.success());
+    assert_eq!(
+        std::fs::read_to_string(&junit_path).unwrap(),
+        junit(&replay("failing.log"))
+    );
+}
+
+#[test]
+fn cli_requires_one_source() {
+    let runner = || Command::new(env!("CARGO_BIN_EXE_atsamd-tests-runner"));
+
+    assert!(!runner().output().unwrap().status.success());
+    assert!(
+        !runner()
+            .args(["--port", "/dev/null", "--log", "x.log"])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    );
+}
@@ -0,0 +1,147 @@
+//! Procedural macros for the test programs.
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{
+    Error, Expr, ExprLit, Ident, ItemMod, Lit, Path, Token,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+};
+
+/// The arguments of [`stress_tasks`].
+struct StressArgs {
+    count: u32,
+    spawn: Option<Expr>,
+    task: Path,
+    period: Expr,
+    position: Expr,
+    resource: Ident,
+}
+impl Parse for StressArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut count = None;
+        let mut spawn = None;
+        let mut task = None;
+        let mut period = None;
+        let mut position = None;
+        let mut resource = None;
+
+        while !input.is_empty() {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+            match name.to_string().as_str() {
+                "count" => count = Some(parse_count(input.parse()?)?),
+                "spawn" => spawn = Some(input.parse()?),
+                "task" => task = Some(input.parse()?),
+                "period" => period = Some(input.parse()?),
+                "position" => position = Some(input.parse()?),
+                "resource" => resource = Some(input.parse()?),
+                _ => return Err(Error::new(name.span(), "unknown argument")),
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        let missing = |name| Error::new(Span::call_site(), format!("missing `{name}`"));
+        Ok(Self {
+            count: count.ok_or_else(|| missing("count"))?,
+            spawn,
+            task: task.ok_or_else(|| missing("task"))?,
+            period: period.ok_or_else(|| missing("period"))?,
+            position: position.unwrap_or_else(|| parse_quote!(i)),
+            resource: resource.unwrap_or_else(|| Ident::new("display", Span::call_site())),
+        })
+    }
+}
+
+/// Takes the task count from an integer literal, which is all that can be counted while the
+/// attribute expands.
+fn parse_count(count: Expr) -> syn::Result<u32> {
+    match &count {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        _ => Err(Error::new_spanned(
+            count,
+            "`count` has to be an integer literal, use `spawn` to run fewer tasks by a const",
+        )),
+    }
+}
+
+/// Adds stress test tasks to an RTIC app, along with a `spawn_stress_tasks` function that
+/// spawns them.
+///
+/// Put it above `#[rtic::app]`, so that RTIC sees the tasks it adds:
+/// ```ignore
+/// #[stress_tasks(count = 768, spawn = STRESS_TASKS, task = test_task, period = BASE_PERIOD_MS)]
+/// #[rtic::app(device = pac, dispatchers = [EVSYS_0])]
+/// mod app { ... }
+/// ```
+///
+/// Task `i`, counting from 0, is named `stress_{i + 1}` and awaits
+/// `task(cx.shared.resource, position, period)`, where the `position` and `period` expressions
+/// can use `i` as a `u32`. The arguments are:
+/// - `count`: How many tasks there are, as a literal. RTIC needs every task declared when the app
+///   expands, which is before any const can be evaluated.
+/// - `spawn`: How many of the tasks are spawned, which can be any const expression of at most
+///   `count`, such as a const that a feature picks. All of them are spawned unless given.
+/// - `task`: The async function that each task runs.
+/// - `period`: The period of the task in milliseconds.
+/// - `position`: Where the task draws its patch, which is `i` unless given.
+/// - `resource`: The shared resource passed to the task, which is `display` unless given.
+#[proc_macro_attribute]
+pub fn stress_tasks(args: TokenStream, item: TokenStream) -> TokenStream {
+    let StressArgs {
+        count,
+        spawn,
+        task,
+        period,
+        position,
+        resource,
+    } = parse_macro_input!(args as StressArgs);
+    let mut app = parse_macro_input!(item as ItemMod);
+
+    let Some((_, items)) = app.content.as_mut() else {
+        return Error::new_spanned(&app, "the app module has to have a body")
+            .to_compile_error()
+            .into();
+    };
+
+    let names: Vec<_> = (1..=count).map(|n| format_ident!("stress_{n}")).collect();
+    items.push(match spawn {
+        None => parse_quote! {
+            #[inline]
+            fn spawn_stress_tasks() {
+                #(#names::spawn().ok().unwrap();)*
+            }
+        },
+        Some(spawn) => {
+            let indices = 0..count;
+            parse_quote! {
+                #[inline]
+                fn spawn_stress_tasks() {
+                    const SPAWN: u32 = #spawn;
+                    const _: () = assert!(SPAWN <= #count, "`spawn` is more than `count`");
+                    #(
+                        if #indices < SPAWN {
+                            #names::spawn().ok().unwrap();
+                        }
+                    )*
+                }
+            }
+        }
+    });
+    for (i, name) in (0..count).zip(&names) {
+        items.push(parse_quote! {
+            #[task(priority = 1, shared = [#resource])]
+            async fn #name(cx: #name::Context) {
+                #[allow(unused_variables)]
+                let i: u32 = #i;
+                #task(cx.shared.#resource, #position, #period).await
+            }
+        });
+    }
+
+    quote!(#app).into()
+}
@@ -1,18 +1,66 @@
 use crate::hal::{ehal::digital::InputPin, gpio};
-use derive_more::From;
-use shared::prelude::*;
+use shared::{
+    input::{Debouncer, POLL_MS},
+    prelude::*,
+};
 
 type ButtonRaw<P> = gpio::Pin<P, gpio::Input<gpio::PullUp>>;
 
-#[derive(From)]
-pub struct Button<P: gpio::PinId>(ButtonRaw<P>);
-impl<P: gpio::PinId> Input for Button<P> {
-    fn wait_for_button(&mut self) {
-        // Wait for button to be unpressed
-        while self.0.is_low().unwrap() {}
+/// Cycles of the 48 MHz CPU clock in each poll interval.
+const POLL_CYCLES: u32 = 48_000 * POLL_MS;
 
-        // Now wait for it to be pressed
-        while self.0.is_high().unwrap() {}
+/// A pin connected to one of the display FeatherWing buttons.
+pub trait ButtonPin: gpio::PinId {
+    const ID: ButtonId;
+}
+impl ButtonPin for gpio::PA07 {
+    const ID: ButtonId = ButtonId::A;
+}
+impl ButtonPin for gpio::PA20 {
+    const ID: ButtonId = ButtonId::B;
+}
+impl ButtonPin for gpio::PA15 {
+    const ID: ButtonId = ButtonId::C;
+}
+
+pub struct Button<P: ButtonPin> {
+    pin: ButtonRaw<P>,
+    debouncer: Debouncer,
+}
+impl<P: ButtonPin> Button<P> {
+    /// Samples the button as a bitmask of [`ButtonId`]s. The buttons pull the pin low.
+    fn sample(&mut self) -> u8 {
+        if self.pin.is_low().unwrap() {
+            P::ID.mask()
+        } else {
+            0
+        }
+    }
+}
+impl<P: ButtonPin> From<ButtonRaw<P>> for Button<P> {
+    fn from(pin: ButtonRaw<P>) -> Self {
+        Self {
+            pin,
+            debouncer: Debouncer::new(),
+        }
+    }
+}
+impl<P: ButtonPin> Input for Button<P> {
+    const NAV_HELP: &'static str = "Press to select";
+
+    fn poll_event(&mut self) -> Option<ButtonEvent> {
+        let sample = self.sample();
+        self.debouncer.update(sample, POLL_MS);
+        self.debouncer.next_event()
+    }
+
+    fn pause(&mut self) {
+        cortex_m::asm::delay(POLL_CYCLES);
+    }
+
+    fn nav(_button: ButtonId) -> Option<Nav> {
+        // There is nowhere to go with a single button
+        Some(Nav::Select)
     }
 }
 
@@ -20,20 +68,150 @@ pub struct Buttons {
     pub button_a: Button<gpio::PA07>,
     pub button_b: Button<gpio::PA20>,
     pub button_c: Button<gpio::PA15>,
+    debouncer: Debouncer,
+}
+impl Buttons {
+    pub fn new(
+        button_a: ButtonRaw<gpio::PA07>,
+        button_b: ButtonRaw<gpio::PA20>,
+        button_c: ButtonRaw<gpio::PA15>,
+    ) -> Self {
+        Self {
+            button_a: button_a.into(),
+            button_b: button_b.into(),
+            button_c: button_c.into(),
+            debouncer: Debouncer::new(),
+        }
+    }
 }
 
 impl Input for Buttons {
-    fn wait_for_button(&mut self) {
-        // Wait for all buttons to be unpressed
-        while self.button_a.0.is_low().unwrap()
-            || self.button_b.0.is_low().unwrap()
-            || self.button_c.0.is_low().unwrap()
-        {}
-
-        // Now wait for any to be pressed
-        while self.button_a.0.is_high().unwrap()
-            && self.button_b.0.is_high().unwrap()
-            && self.button_c.0.is_high().unwrap()
-        {}
+    const NAV_HELP: &'static str = "A/C: move, B: select";
+
+    fn poll_event(&mut self) -> Option<ButtonEvent> {
+        let sample = self.button_a.sample() | self.button_b.sample() | self.button_c.sample();
+        self.debouncer.update(sample, POLL_MS);
+        self.debouncer.next_event()
+    }
+
+    fn pause(&mut self) {
+        cortex_m::asm::delay(POLL_CYCLES);
+    }
+
+    fn nav(button: ButtonId) -> Option<Nav> {
+        match button {
+            ButtonId::A => Some(Nav::Previous),
+            ButtonId::B => Some(Nav::Select),
+            ButtonId::C => Some(Nav::Next),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "rtic")]
+pub use self::async_buttons::AsyncButtons;
+
+#[cfg(feature = "rtic")]
+mod async_buttons {
+    use super::{ButtonRaw, Buttons};
+    use crate::hal::{
+        clock::GenericClockController,
+        ehal::digital::InputPin,
+        eic::{self, Ch4, Ch7, Ch15, Eic, EicFuture, ExtInt, Sense},
+        gpio,
+    };
+    use core::{future::poll_fn, pin::pin, task::Poll};
+    use shared::{
+        input::{Debouncer, POLL_MS},
+        prelude::*,
+    };
+
+    crate::hal::bind_interrupts!(struct Irqs {
+        EIC => eic::InterruptHandler;
+    });
+
+    type AsyncButton<P, C> = ExtInt<ButtonRaw<P>, C, EicFuture>;
+
+    /// The buttons with their pins as external interrupts, so that a task can wait for a press
+    /// without polling.
+    pub struct AsyncButtons {
+        button_a: AsyncButton<gpio::PA07, Ch7>,
+        button_b: AsyncButton<gpio::PA20, Ch4>,
+        button_c: AsyncButton<gpio::PA15, Ch15>,
+        debouncer: Debouncer,
+    }
+    impl AsyncButtons {
+        fn sample(&mut self) -> u8 {
+            let mut sample = 0;
+            if self.button_a.is_low().unwrap() {
+                sample |= ButtonId::A.mask();
+            }
+            if self.button_b.is_low().unwrap() {
+                sample |= ButtonId::B.mask();
+            }
+            if self.button_c.is_low().unwrap() {
+                sample |= ButtonId::C.mask();
+            }
+            sample
+        }
+
+        /// Waits for any of the pins to change.
+        async fn wait_for_edge(&mut self) {
+            let mut a = pin!(self.button_a.wait(Sense::Both));
+            let mut b = pin!(self.button_b.wait(Sense::Both));
+            let mut c = pin!(self.button_c.wait(Sense::Both));
+
+            poll_fn(|cx| {
+                if a.as_mut().poll(cx).is_ready()
+                    || b.as_mut().poll(cx).is_ready()
+                    || c.as_mut().poll(cx).is_ready()
+                {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await
+        }
+    }
+
+    impl Buttons {
+        /// Turns the button pins into external interrupts for use from async tasks.
+        pub fn into_async(
+            self,
+            clocks: &mut GenericClockController,
+            pm: &mut pac::Pm,
+            eic: pac::Eic,
+        ) -> AsyncButtons {
+            let gclk0 = clocks.gclk0();
+            let eic_clock = clocks.eic(&gclk0).unwrap();
+            let channels = Eic::new(pm, eic_clock, eic).into_future(Irqs).split();
+
+            AsyncButtons {
+                button_a: channels.7.with_pin(self.button_a.pin),
+                button_b: channels.4.with_pin(self.button_b.pin),
+                button_c: channels.15.with_pin(self.button_c.pin),
+                debouncer: Debouncer::new(),
+            }
+        }
+    }
+
+    impl AsyncInput for AsyncButtons {
+        async fn event(&mut self) -> ButtonEvent {
+            loop {
+                if let Some(event) = self.debouncer.next_event() {
+                    return event;
+                }
+
+                // Sleep until a button changes, then poll until things settle down again
+                if self.debouncer.is_idle() {
+                    self.wait_for_edge().await;
+                } else {
+                    Mono::delay_ms(POLL_MS).await;
+                }
+                let sample = self.sample();
+                self.debouncer.update(sample, POLL_MS);
+            }
+        }
     }
 }
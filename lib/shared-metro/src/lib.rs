@@ -6,13 +6,18 @@ use hal::{
     clock::{ClockGenId, ClockSource, GenericClockController, RtcClock},
     delay::Delay,
     gpio::{PA24, PA25, Pin, Reset},
+    time::Hertz,
 };
-pub use input::{Button, Buttons};
+#[cfg(feature = "rtic")]
+pub use input::AsyncButtons;
+pub use input::{Button, ButtonPin, Buttons};
 use shared::prelude::*;
 
 mod display;
 mod input;
 pub mod tests;
+#[cfg(feature = "usb")]
+pub mod usb;
 
 pub mod prelude {
     pub use super::{Buttons, DisplayDriver, Screens, SetupPackage};
@@ -27,9 +32,12 @@ pub struct SetupPackage {
     pub display: DisplayDriver,
     pub buttons: Buttons,
     pub red_led: RedLed,
+    pub eic: pac::Eic,
     rtc: Option<pac::Rtc>,
     pub tc4: pac::Tc4,
     pub clocks: GenericClockController,
+    /// The rate of GCLK0, which clocks the core and SysTick.
+    pub sysclk: Hertz,
     pub pm: pac::Pm,
     pub usb: pac::Usb,
     pub usb_dm: Pin<PA24, Reset>,
@@ -37,7 +45,18 @@ pub struct SetupPackage {
     pub nvic: pac::NVIC,
 }
 impl SetupPackage {
-    pub fn new(mut peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
+    /// Sets up the board, first showing the panic from before the last reset, if there was one.
+    pub fn new(peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
+        let mut pkg = Self::setup(peripherals, core);
+        let mut screens = Screens::new(pkg.display, pkg.buttons);
+        screens.show_previous_panic();
+        (pkg.display, pkg.buttons) = screens.free();
+        pkg
+    }
+
+    /// Sets up the board, leaving any panic from before the last reset alone, as the panic
+    /// handler does.
+    pub(crate) fn setup(mut peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
         use hal::fugit::RateExtU32;
         use sh1107::prelude::*;
 
@@ -47,6 +66,7 @@ impl SetupPackage {
             &mut peripherals.sysctrl,
             &mut peripherals.nvmctrl,
         );
+        let sysclk = clocks.gclk0().freq();
         let pins = Pins::new(peripherals.port);
 
         // Setup the delay
@@ -74,15 +94,17 @@ impl SetupPackage {
         Self {
             delay,
             display: display.into(),
-            buttons: Buttons {
-                button_a: pins.d9.into_pull_up_input().into(),
-                button_b: pins.d6.into_pull_up_input().into(),
-                button_c: pins.d5.into_pull_up_input().into(),
-            },
+            buttons: Buttons::new(
+                pins.d9.into_pull_up_input(),
+                pins.d6.into_pull_up_input(),
+                pins.d5.into_pull_up_input(),
+            ),
             red_led: pins.d13.into(),
+            eic: peripherals.eic,
             rtc: Some(peripherals.rtc),
             tc4: peripherals.tc4,
             clocks,
+            sysclk,
             pm: peripherals.pm,
             usb: peripherals.usb,
             usb_dm: pins.usb_dm,
@@ -17,5 +17,114 @@ pub mod async_stress {
         async_stress::test_task::<10, _, _>(COLORS, display, position, delay_ms).await;
     }
 
-    pub use async_stress::clock_task;
+    pub use async_stress::{button_task, clock_task};
+}
+
+#[cfg(feature = "dma")]
+pub mod dmac {
+    use crate::Screens;
+    use hal::dmac::{Ch0, Channel, ReadyFuture};
+    use shared::prelude::*;
+
+    pub const LENGTH: usize = 50;
+
+    /// The async DMAC channel and the buffers that the test copies between.
+    pub struct Resources {
+        pub channel: Channel<Ch0, ReadyFuture>,
+        source: [u8; LENGTH],
+        dest: [u8; LENGTH],
+    }
+    impl Resources {
+        pub fn new(channel: Channel<Ch0, ReadyFuture>) -> Self {
+            Self {
+                channel,
+                source: core::array::from_fn(|i| i as u8),
+                dest: [0; LENGTH],
+            }
+        }
+    }
+
+    /// Runs [`ScreensGen::check_dmac_wakeups`] on the channel, with fresh buffers.
+    pub fn run_async(screens: &mut Screens, resources: &mut Resources) {
+        let Resources {
+            channel,
+            source,
+            dest,
+        } = resources;
+        dest.fill(0);
+        screens.check_dmac_wakeups(channel, source, dest);
+    }
+}
+
+#[cfg(not(feature = "rtic"))]
+pub mod wakers {
+    use hal::timer::TimerFuture;
+    use shared::prelude::*;
+
+    /// The async timer for [`ScreensGen::run_wakers_test`], whose TC5 interrupt the program binds.
+    pub type Timer = TimerFuture<pac::Tc5>;
+}
+
+pub mod delay_ns {
+    use hal::clock::Tc4Tc5Clock;
+    use shared::{prelude::*, tests::Stopwatch};
+
+    /// Times delays and the monotonic with TC4 and TC5 chained into a 32-bit counter, as the
+    /// Cortex-M0+ has no cycle counter.
+    ///
+    /// The RTC count is not the reference for the delays: at 1 kHz it cannot time anything
+    /// shorter than several milliseconds, and the RTC delays are among those under test, which
+    /// would have to be skipped rather than timed against themselves.
+    ///
+    /// The count is not divided, so that a microsecond is dozens of ticks, and at 48 MHz it still
+    /// takes over a minute to wrap around.
+    pub struct TcStopwatch {
+        tc: pac::Tc4,
+        rate_hz: u32,
+    }
+    impl TcStopwatch {
+        pub fn new(tc: pac::Tc4, clock: &Tc4Tc5Clock, pm: &mut pac::Pm) -> Self {
+            pm.apbcmask()
+                .modify(|_, w| w.tc4_().set_bit().tc5_().set_bit());
+
+            let count = tc.count32();
+            count.ctrla().write(|w| w.swrst().set_bit());
+            while count.status().read().syncbusy().bit_is_set() {}
+            count
+                .ctrla()
+                .write(|w| w.mode().count32().prescaler().div1().enable().set_bit());
+            while count.status().read().syncbusy().bit_is_set() {}
+            // Keep the count synchronized, so that reading it does not have to wait each time
+            count
+                .readreq()
+                .write(|w| unsafe { w.rcont().set_bit().addr().bits(0x10) });
+
+            Self {
+                tc,
+                rate_hz: clock.freq().to_Hz(),
+            }
+        }
+
+        /// Resets TC4, which leaves TC5 to count on its own again.
+        pub fn free(self) -> pac::Tc4 {
+            let count = self.tc.count32();
+            count.ctrla().write(|w| w.swrst().set_bit());
+            while count.status().read().syncbusy().bit_is_set() {}
+            self.tc
+        }
+    }
+    impl Stopwatch for TcStopwatch {
+        fn rate_hz(&self) -> u32 {
+            self.rate_hz
+        }
+
+        fn ticks(&mut self) -> u32 {
+            self.tc.count32().count().read().bits()
+        }
+
+        fn uses_core_clock(&self) -> bool {
+            // The programs clock the timers from GCLK0, as the core is
+            true
+        }
+    }
 }
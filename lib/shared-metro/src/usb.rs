@@ -0,0 +1,39 @@
+//! USB serial output using `atsamd-usb-serial`, which drives the device from the USB interrupt.
+use atsamd_usb_serial::prelude::*;
+use bsp::usb_allocator;
+use shared::prelude::*;
+
+/// Sets up the USB serial device.
+pub fn usb_serial(
+    usb: pac::Usb,
+    usb_dm: hal::gpio::Pin<hal::gpio::PA24, hal::gpio::Reset>,
+    usb_dp: hal::gpio::Pin<hal::gpio::PA25, hal::gpio::Reset>,
+    clocks: &mut hal::clock::GenericClockController,
+    pm: &mut pac::Pm,
+    nvic: &mut pac::NVIC,
+) -> UsbSerial {
+    UsbSerial::new(
+        nvic,
+        usb_allocator(usb, clocks, pm, usb_dm, usb_dp),
+        StringDescriptors::new(LangID::EN)
+            .manufacturer("Fake company")
+            .product("Serial port")
+            .serial_number("TEST"),
+        UsbVidPid(0x16c0, 0x27dd),
+        true,
+    )
+    .unwrap()
+}
+
+/// Sets up the USB serial device and emits test results to it.
+pub fn init_results(
+    usb: pac::Usb,
+    usb_dm: hal::gpio::Pin<hal::gpio::PA24, hal::gpio::Reset>,
+    usb_dp: hal::gpio::Pin<hal::gpio::PA25, hal::gpio::Reset>,
+    clocks: &mut hal::clock::GenericClockController,
+    pm: &mut pac::Pm,
+    nvic: &mut pac::NVIC,
+) {
+    let serial = usb_serial(usb, usb_dm, usb_dp, clocks, pm, nvic);
+    set_result_sink(cortex_m::singleton!(: UsbSerial = serial).unwrap());
+}
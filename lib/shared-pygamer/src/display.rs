@@ -35,8 +35,12 @@ impl Display for DisplayDriver {
 
 #[panic_handler]
 fn panic(info: &core::panic::PanicInfo) -> ! {
+    // Keep the panic for the next boot, in case the display is no help
+    record_panic(info);
+    // Do this before setting up the board again, since that may disrupt USB
+    emit_results(|r| r.panic(info));
     let pkg = unsafe {
-        crate::SetupPackage::new(pac::Peripherals::steal(), pac::CorePeripherals::steal())
+        crate::SetupPackage::setup(pac::Peripherals::steal(), pac::CorePeripherals::steal())
     };
 
     panic_display(pkg.display, info);
@@ -1,25 +1,83 @@
 use crate::bsp::{ButtonReader, Keys};
-use derive_more::From;
-use shared::prelude::*;
+use shared::{
+    input::{Debouncer, POLL_MS},
+    prelude::*,
+};
 
-#[derive(From)]
-pub struct Buttons(ButtonReader);
+/// Cycles of the 120 MHz CPU clock in each poll interval.
+const POLL_CYCLES: u32 = 120_000 * POLL_MS;
+
+pub struct Buttons {
+    reader: ButtonReader,
+    /// The buttons held down according to the events of the reader.
+    sample: u8,
+    debouncer: Debouncer,
+}
 impl Buttons {
     pub fn free(self) -> ButtonReader {
-        self.0
+        self.reader
+    }
+}
+impl From<ButtonReader> for Buttons {
+    fn from(reader: ButtonReader) -> Self {
+        Self {
+            reader,
+            sample: 0,
+            debouncer: Debouncer::new(),
+        }
     }
 }
 
 impl Input for Buttons {
-    // Note that this will block the thread until a button is pressed!
-    fn wait_for_button(&mut self) {
-        'main: loop {
-            for event in self.0.events() {
-                match event {
-                    Keys::StartDown | Keys::ADown => break 'main,
-                    _ => {}
-                }
+    const NAV_HELP: &'static str = "SELECT/B: move, A: run";
+
+    fn poll_event(&mut self) -> Option<ButtonEvent> {
+        for key in self.reader.events() {
+            let (button, down) = match key {
+                Keys::ADown => (ButtonId::A, true),
+                Keys::AUp => (ButtonId::A, false),
+                Keys::BDown => (ButtonId::B, true),
+                Keys::BUp => (ButtonId::B, false),
+                Keys::SelectDown => (ButtonId::Select, true),
+                Keys::SelectUp => (ButtonId::Select, false),
+                Keys::StartDown => (ButtonId::Start, true),
+                Keys::StartUp => (ButtonId::Start, false),
+            };
+            if down {
+                self.sample |= button.mask();
+            } else {
+                self.sample &= !button.mask();
+            }
+        }
+
+        self.debouncer.update(self.sample, POLL_MS);
+        self.debouncer.next_event()
+    }
+
+    fn pause(&mut self) {
+        cortex_m::asm::delay(POLL_CYCLES);
+    }
+
+    fn nav(button: ButtonId) -> Option<Nav> {
+        match button {
+            ButtonId::B => Some(Nav::Previous),
+            ButtonId::Select => Some(Nav::Next),
+            ButtonId::A | ButtonId::Start => Some(Nav::Select),
+            ButtonId::C => None,
+        }
+    }
+}
+
+/// The buttons sit behind a shift register with no interrupt, so this polls with the monotonic
+/// delay, which lets other tasks run in between.
+#[cfg(feature = "rtic")]
+impl AsyncInput for Buttons {
+    async fn event(&mut self) -> ButtonEvent {
+        loop {
+            if let Some(event) = self.poll_event() {
+                return event;
             }
+            Mono::delay_ms(POLL_MS).await;
         }
     }
 }
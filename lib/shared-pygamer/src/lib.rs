@@ -3,13 +3,15 @@
 use bsp::{Pins, RedLed, pins::USB};
 use display::DisplayDriver;
 use embedded_graphics::prelude::*;
-use hal::{clock::GenericClockController, delay::Delay};
+use hal::{clock::GenericClockController, delay::Delay, time::Hertz};
 use input::Buttons;
 use shared::prelude::*;
 
 mod display;
 mod input;
 pub mod tests;
+#[cfg(feature = "usb")]
+pub mod usb;
 
 pub mod prelude {
     #[cfg(feature = "neopixels")]
@@ -36,14 +38,29 @@ pub struct SetupPackage {
     pub tc4: pac::Tc4,
     rtc: Option<pac::Rtc>,
     pub clocks: GenericClockController,
+    /// The rate of GCLK0, which clocks the core and SysTick.
+    pub sysclk: Hertz,
     pub mclk: pac::Mclk,
     pub osc32kctrl: pac::Osc32kctrl,
     pub usb: pac::Usb,
     pub usb_pins: USB,
     pub nvic: pac::NVIC,
+    pub dwt: pac::DWT,
+    pub dcb: pac::DCB,
 }
 impl SetupPackage {
-    pub fn new(mut peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
+    /// Sets up the board, first showing the panic from before the last reset, if there was one.
+    pub fn new(peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
+        let mut pkg = Self::setup(peripherals, core);
+        let mut screens = Screens::new(pkg.display, pkg.buttons);
+        screens.show_previous_panic();
+        (pkg.display, pkg.buttons) = screens.free();
+        pkg
+    }
+
+    /// Sets up the board, leaving any panic from before the last reset alone, as the panic
+    /// handler does.
+    pub(crate) fn setup(mut peripherals: pac::Peripherals, core: pac::CorePeripherals) -> Self {
         // NOTE: We would like to use the v2 of the clock module, but this is not yet integrated
         // into the rest of the HAL or the `pygamer` BSP. For example, the display `init` method
         // below requires clock v1 parameters.
@@ -63,6 +80,8 @@ impl SetupPackage {
             &mut peripherals.nvmctrl,
         );
 
+        let sysclk = clocks.gclk0().freq();
+
         let pins = Pins::new(peripherals.port).split();
         let mut delay = Delay::new(core.SYST, &mut clocks);
         // Here is how this can be initialized using the clock v2 API instead
@@ -101,11 +120,14 @@ impl SetupPackage {
             tc4: peripherals.tc4,
             rtc: Some(peripherals.rtc),
             clocks,
+            sysclk,
             mclk: peripherals.mclk,
             osc32kctrl: peripherals.osc32kctrl,
             usb: peripherals.usb,
             usb_pins: pins.usb,
             nvic: core.NVIC,
+            dwt: core.DWT,
+            dcb: core.DCB,
         }
     }
 
@@ -31,5 +31,231 @@ pub mod async_stress {
         async_stress::test_task::<10, _, _>(COLORS, display, position, delay_ms).await;
     }
 
-    pub use async_stress::clock_task;
+    pub use async_stress::{button_task, clock_task};
+}
+
+pub mod dsu {
+    use crate::Screens;
+    use aligned::{A4, Aligned};
+    use hal::dsu::Dsu;
+    use shared::prelude::*;
+
+    type Data = [u8; 24];
+
+    // Some data in flash, the length is divisible by 4 so should be word aligned
+    static DATA: Aligned<A4, Data> = Aligned(*b"This is a test string!!!");
+
+    /// The CRC32 for the above data string
+    pub const CRC32: u32 = 0xE8C27689;
+
+    /// Calculates and verifies the CRC32 of the data in both flash and RAM.
+    pub fn run(screens: &mut Screens, dsu: &mut Dsu) {
+        // Some data in RAM
+        let data = DATA;
+
+        let flash_addr = DATA.as_ptr() as u32;
+        let flash_crc = dsu.crc32(flash_addr, DATA.len() as u32).unwrap();
+        let ram_addr = data.as_ptr() as u32;
+        let ram_crc = dsu.crc32(ram_addr, data.len() as u32).unwrap();
+
+        check_eq!(screens, flash_crc, CRC32);
+        check_eq!(screens, ram_crc, CRC32);
+
+        let mut writer = screens.new_screen();
+        writeln!(writer, "Flash address: {flash_addr:08X}").unwrap();
+        writeln!(writer, "Flash CRC32: {flash_crc:08X}").unwrap();
+        writeln!(writer, "RAM address: {ram_addr:08X}").unwrap();
+        writeln!(writer, "RAM CRC32: {ram_crc:08X}").unwrap();
+        screens.wait_for_button();
+    }
+}
+
+#[cfg(feature = "aes")]
+pub mod aes {
+    use crate::Screens;
+    use ::aes::{
+        Block,
+        cipher::{BlockDecrypt, BlockEncrypt},
+    };
+    use shared::prelude::*;
+
+    /// Encrypts a block and decrypts it back.
+    pub fn run<C: BlockEncrypt + BlockDecrypt>(screens: &mut Screens, cipher: &C) {
+        let mut block = Block::default();
+
+        // This copies the entire block
+        let block_copy = block;
+
+        // Encrypt block in-place
+        cipher.encrypt_block(&mut block);
+        let encrypted = block;
+
+        // Decrypt it back
+        cipher.decrypt_block(&mut block);
+
+        // Verify that the encrypted block is different and the decrypted one is the same as it was
+        check!(screens, encrypted != block_copy);
+        check_eq!(screens, block.as_slice(), block_copy.as_slice());
+    }
+}
+
+#[cfg(feature = "dma")]
+pub mod dmac {
+    use crate::Screens;
+    use hal::{
+        async_hal::interrupts::{Binding, DMAC},
+        clock::v2::{ahb::AhbClk, types::Dmac},
+        dmac::{
+            DmaController, InterruptHandler, PriorityLevel, Transfer,
+            dma_controller::{TriggerAction, TriggerSource},
+        },
+    };
+    use shared::prelude::*;
+
+    pub const LENGTH: usize = 50;
+    pub type Buffer = &'static mut [u8; LENGTH];
+
+    /// The DMAC and buffers used by the tests, which are handed back so that they can run again.
+    ///
+    /// Each test sets up the controller for itself and frees it when done, as the blocking and
+    /// async transfers need it set up differently.
+    pub struct Resources {
+        pub dmac: pac::Dmac,
+        pub clock: AhbClk<Dmac>,
+        pub buffers: [Buffer; 4],
+    }
+    impl Resources {
+        /// Takes the buffers from statics, so this can only be called once.
+        pub fn new(dmac: pac::Dmac, clock: AhbClk<Dmac>) -> Self {
+            Self {
+                dmac,
+                clock,
+                buffers: [
+                    cortex_m::singleton!(: [u8; LENGTH] = [0; LENGTH]).unwrap(),
+                    cortex_m::singleton!(: [u8; LENGTH] = [0; LENGTH]).unwrap(),
+                    cortex_m::singleton!(: [u8; LENGTH] = [0; LENGTH]).unwrap(),
+                    cortex_m::singleton!(: [u8; LENGTH] = [0; LENGTH]).unwrap(),
+                ],
+            }
+        }
+    }
+
+    fn fill(buffer: &mut [u8], first: u8) {
+        for (i, byte) in buffer.iter_mut().enumerate() {
+            *byte = first.wrapping_add(i as u8);
+        }
+    }
+
+    /// Copies a buffer with a memory-to-memory transfer, then recycles the transfer to copy
+    /// another one.
+    pub fn run(screens: &mut Screens, resources: Resources) -> Resources {
+        let Resources {
+            dmac,
+            clock,
+            buffers: [source, dest, new_source, new_dest],
+        } = resources;
+        fill(source, 0);
+        dest.fill(0);
+        fill(new_source, 0x80);
+        new_dest.fill(0);
+
+        let mut dmac = DmaController::new(dmac, clock);
+        let mut channels = dmac.split();
+        let channel = channels.0.init(PriorityLevel::Lvl0);
+
+        // Setup a DMA transfer (memory-to-memory -> incrementing source, incrementing destination)
+        let xfer = Transfer::new(channel, source, dest, false)
+            .unwrap()
+            .begin(TriggerSource::Disable, TriggerAction::Block);
+        let (channel, source, dest) = xfer.wait();
+        check_eq!(screens, dest[..], source[..]);
+
+        // Transfer cycling
+        let mut xfer = Transfer::new(channel, source, dest, false)
+            .unwrap()
+            .begin(TriggerSource::Disable, TriggerAction::Block);
+        while !xfer.complete() {}
+        let (source, dest) = xfer.recycle(new_source, new_dest).unwrap();
+        let (channel, new_source, new_dest) = xfer.wait();
+        check_eq!(screens, new_dest[..], new_source[..]);
+
+        channels.0 = channel.into();
+        let (dmac, clock) = dmac.free(channels);
+        Resources {
+            dmac,
+            clock,
+            buffers: [source, dest, new_source, new_dest],
+        }
+    }
+
+    /// Sets up the controller for async transfers with the interrupts bound for `irqs`, for
+    /// [`ScreensGen::check_dmac_wakeups`] on channel 0.
+    pub fn run_async<I>(screens: &mut Screens, resources: Resources, irqs: I) -> Resources
+    where
+        I: Binding<DMAC, InterruptHandler>,
+    {
+        let Resources {
+            dmac,
+            clock,
+            buffers: [source, dest, new_source, new_dest],
+        } = resources;
+        fill(source, 0x40);
+        dest.fill(0);
+
+        let mut dmac = DmaController::new(dmac, clock).into_future(irqs);
+        let mut channels = dmac.split();
+        let mut channel = channels.0.init(PriorityLevel::Lvl0);
+        screens.check_dmac_wakeups(&mut channel, source, dest);
+
+        channels.0 = channel.into();
+        let (dmac, clock) = dmac.free(channels);
+        Resources {
+            dmac,
+            clock,
+            buffers: [source, dest, new_source, new_dest],
+        }
+    }
+}
+
+#[cfg(not(feature = "rtic"))]
+pub mod wakers {
+    use hal::timer::TimerFuture;
+    use shared::prelude::*;
+
+    /// TC5 with async delays for [`ScreensGen::run_wakers_test`].
+    pub type Timer = TimerFuture<pac::Tc5>;
+}
+
+pub mod delay_ns {
+    use shared::{prelude::*, tests::Stopwatch};
+
+    /// Times delays with the DWT cycle counter of the Cortex-M4.
+    pub struct DwtStopwatch {
+        dwt: pac::DWT,
+        cpu_hz: u32,
+    }
+    impl DwtStopwatch {
+        pub fn new(mut dwt: pac::DWT, dcb: &mut pac::DCB, cpu_hz: u32) -> Self {
+            dcb.enable_trace();
+            dwt.enable_cycle_counter();
+            Self { dwt, cpu_hz }
+        }
+
+        pub fn free(self) -> pac::DWT {
+            self.dwt
+        }
+    }
+    impl Stopwatch for DwtStopwatch {
+        fn rate_hz(&self) -> u32 {
+            self.cpu_hz
+        }
+
+        fn ticks(&mut self) -> u32 {
+            pac::DWT::cycle_count()
+        }
+
+        fn uses_core_clock(&self) -> bool {
+            true
+        }
+    }
 }
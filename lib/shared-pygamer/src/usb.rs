@@ -0,0 +1,198 @@
+//! USB serial output, which is driven by the USB interrupts.
+use bsp::pins::USB;
+use core::cell::OnceCell;
+use core::sync::atomic;
+use cortex_m::peripheral::NVIC;
+use hal::{clock::GenericClockController, usb::UsbBus};
+use pac::interrupt;
+use shared::prelude::*;
+use usb_device::{UsbError, bus::UsbBusAllocator, prelude::*};
+use usbd_serial::{SerialPort, USB_CLASS_CDC};
+
+/// How many times to poll the device for a full write buffer to drain before giving up.
+const WRITE_RETRIES: u32 = 10_000;
+
+static mut USB_ALLOCATOR: OnceCell<UsbBusAllocator<UsbBus>> = OnceCell::new();
+static mut USB_BUS: OnceCell<UsbDevice<UsbBus>> = OnceCell::new();
+static mut USB_SERIAL: OnceCell<SerialPort<UsbBus>> = OnceCell::new();
+static USER_PRESENT: atomic::AtomicBool = atomic::AtomicBool::new(false);
+
+/// Sets up the USB serial device and enables the USB interrupts.
+pub fn init(
+    usb_pins: USB,
+    usb: pac::Usb,
+    clocks: &mut GenericClockController,
+    mclk: &mut pac::Mclk,
+    nvic: &mut pac::NVIC,
+) {
+    let bus_allocator = unsafe {
+        let _ = USB_ALLOCATOR.set(usb_pins.init(usb, clocks, mclk));
+        USB_ALLOCATOR.get().unwrap()
+    };
+
+    unsafe {
+        // Setup the USB device
+        let _ = USB_SERIAL.set(SerialPort::new(bus_allocator));
+        let _ = USB_BUS.set(
+            UsbDeviceBuilder::new(bus_allocator, UsbVidPid(0x16c0, 0x27dd))
+                .strings(&[StringDescriptors::new(LangID::EN)
+                    .manufacturer("Fake company")
+                    .product("Serial port")
+                    .serial_number("TEST")])
+                .expect("Failed to set strings")
+                .device_class(USB_CLASS_CDC)
+                .build(),
+        );
+
+        // Enable the USB interrupts
+        nvic.set_priority(interrupt::USB_OTHER, 1);
+        nvic.set_priority(interrupt::USB_TRCPT0, 1);
+        nvic.set_priority(interrupt::USB_TRCPT1, 1);
+        NVIC::unmask(interrupt::USB_OTHER);
+        NVIC::unmask(interrupt::USB_TRCPT0);
+        NVIC::unmask(interrupt::USB_TRCPT1);
+    }
+}
+
+/// Sets up the USB serial device and emits test results to it.
+pub fn init_results(
+    usb_pins: USB,
+    usb: pac::Usb,
+    clocks: &mut GenericClockController,
+    mclk: &mut pac::Mclk,
+    nvic: &mut pac::NVIC,
+) {
+    init(usb_pins, usb, clocks, mclk, nvic);
+    set_result_sink(cortex_m::singleton!(: SerialWriter = SerialWriter).unwrap());
+}
+
+/// Whether the user has sent anything over the serial port yet.
+pub fn user_present() -> bool {
+    USER_PRESENT.load(atomic::Ordering::Acquire)
+}
+
+/// Borrows the global singleton `UsbSerial` for a brief period with interrupts
+/// disabled
+///
+/// # Arguments
+/// `borrower`: The closure that gets run borrowing the global `UsbSerial`
+///
+/// # Safety
+/// the global singleton `UsbSerial` can be safely borrowed because we disable
+/// interrupts while it is being borrowed, guaranteeing that interrupt handlers
+/// like `USB` cannot mutate `UsbSerial` while we are as well.
+///
+/// # Panic
+/// If `init` has not been called and we haven't initialized our global
+/// singleton `UsbSerial`, we will panic.
+pub fn usbserial_get<T, R>(borrower: T) -> R
+where
+    T: FnOnce(&mut SerialPort<UsbBus>) -> R,
+{
+    usb_free(|_| unsafe {
+        let usb_serial = USB_SERIAL.get_mut().expect("UsbSerial not initialized");
+        borrower(usb_serial)
+    })
+}
+
+/// Execute closure `f` in an interrupt-free context.
+///
+/// This as also known as a "critical section".
+#[inline]
+fn usb_free<F, R>(f: F) -> R
+where
+    F: FnOnce(&cortex_m::interrupt::CriticalSection) -> R,
+{
+    NVIC::mask(interrupt::USB_OTHER);
+    NVIC::mask(interrupt::USB_TRCPT0);
+    NVIC::mask(interrupt::USB_TRCPT1);
+
+    let r = f(&unsafe { cortex_m::interrupt::CriticalSection::new() });
+
+    unsafe {
+        NVIC::unmask(interrupt::USB_OTHER);
+        NVIC::unmask(interrupt::USB_TRCPT0);
+        NVIC::unmask(interrupt::USB_TRCPT1);
+    };
+
+    r
+}
+
+/// Writes the given message out over USB serial.
+///
+/// # Arguments
+/// * println args: variable arguments passed along to `core::write!`
+#[macro_export]
+macro_rules! serial_writeln {
+    ($($tt:tt)+) => {{
+        use core::fmt::Write;
+
+        let mut writer = $crate::usb::SerialWriter;
+        core::write!(writer, $($tt)*).ok();
+        writer.write_str("\r\n").ok();
+    }};
+}
+
+/// Text output to the USB serial port.
+///
+/// Writes poll the device themselves whenever the write buffer is full, so that output still
+/// gets out with interrupts disabled, such as when panicking.
+pub struct SerialWriter;
+impl Write for SerialWriter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let mut bytes = s.as_bytes();
+        let mut retries = 0;
+
+        while !bytes.is_empty() {
+            match usbserial_get(|serial| serial.write(bytes)) {
+                Ok(count) => bytes = &bytes[count..],
+                Err(UsbError::WouldBlock) => {
+                    // Nobody may be listening
+                    retries += 1;
+                    if retries > WRITE_RETRIES {
+                        return Err(core::fmt::Error);
+                    }
+                }
+                Err(_) => return Err(core::fmt::Error),
+            }
+            usb_free(|_| poll_usb());
+        }
+
+        Ok(())
+    }
+}
+
+fn poll_usb() {
+    unsafe {
+        if let Some(usb_dev) = USB_BUS.get_mut()
+            && let Some(serial) = USB_SERIAL.get_mut()
+        {
+            usb_dev.poll(&mut [serial]);
+            let mut buf = [0u8; 64];
+
+            if let Ok(count) = serial.read(&mut buf)
+                && count > 0
+            {
+                USER_PRESENT.store(true, atomic::Ordering::Release);
+
+                // We don't want to echo back
+                //serial.write(&buf[..count]).unwrap();
+            }
+        }
+    }
+}
+
+#[interrupt]
+fn USB_OTHER() {
+    poll_usb();
+}
+
+#[interrupt]
+fn USB_TRCPT0() {
+    poll_usb();
+}
+
+#[interrupt]
+fn USB_TRCPT1() {
+    poll_usb();
+}
@@ -0,0 +1,42 @@
+//! A critical section for the host, so that the result sink can be used by the flows.
+//!
+//! This works like the `std` implementation of `critical-section`, which cannot be used here
+//! since `cortex-m` fixes the restore state to a `u32`.
+use std::{
+    cell::Cell,
+    mem::MaybeUninit,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+static mut GUARD: MaybeUninit<MutexGuard<'static, ()>> = MaybeUninit::uninit();
+
+thread_local! {
+    static IS_LOCKED: Cell<bool> = const { Cell::new(false) };
+}
+
+struct HostCriticalSection;
+critical_section::set_impl!(HostCriticalSection);
+
+unsafe impl critical_section::Impl for HostCriticalSection {
+    unsafe fn acquire() -> u32 {
+        // Nested sections just carry on in the outer one
+        if IS_LOCKED.get() {
+            return 1;
+        }
+
+        let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        // SAFETY: Only the thread holding the lock touches the guard.
+        unsafe { (&raw mut GUARD).write(MaybeUninit::new(guard)) };
+        IS_LOCKED.set(true);
+        0
+    }
+
+    unsafe fn release(nested: u32) {
+        if nested == 0 {
+            IS_LOCKED.set(false);
+            // SAFETY: The guard was stored by the matching `acquire` on this thread.
+            drop(unsafe { (&raw mut GUARD).read().assume_init() });
+        }
+    }
+}
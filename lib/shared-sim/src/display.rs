@@ -0,0 +1,179 @@
+use crate::snapshot::SnapshotColor;
+use embedded_graphics::{
+    mono_font,
+    pixelcolor::{BinaryColor, Rgb565},
+    prelude::*,
+};
+use shared::prelude::*;
+
+/// The geometry, font and colors of a simulated display.
+pub trait Profile: 'static {
+    type Color: SnapshotColor;
+    const SIZE: Size;
+    const FONT: mono_font::MonoFont<'static>;
+    const BACKGROUND_COLOR: Self::Color;
+    const TEXT_COLOR: Self::Color;
+    const PANIC_BACKGROUND_COLOR: Self::Color;
+    const PANIC_TEXT_COLOR: Self::Color;
+}
+
+/// The 128x64 SH1107 OLED FeatherWing used with the Metro M0.
+pub struct Metro;
+impl Profile for Metro {
+    type Color = BinaryColor;
+    const SIZE: Size = Size::new(128, 64);
+    const FONT: mono_font::MonoFont<'static> = mono_font::ascii::FONT_4X6;
+    const BACKGROUND_COLOR: Self::Color = BinaryColor::Off;
+    const TEXT_COLOR: Self::Color = BinaryColor::On;
+    const PANIC_BACKGROUND_COLOR: Self::Color = BinaryColor::On;
+    const PANIC_TEXT_COLOR: Self::Color = BinaryColor::Off;
+}
+
+/// The 160x128 ST7735 display built into the PyGamer.
+pub struct Pygamer;
+impl Profile for Pygamer {
+    type Color = Rgb565;
+    const SIZE: Size = Size::new(160, 128);
+    const FONT: mono_font::MonoFont<'static> = mono_font::ascii::FONT_5X8;
+    const BACKGROUND_COLOR: Self::Color = Rgb565::WHITE;
+    const TEXT_COLOR: Self::Color = Rgb565::BLACK;
+    const PANIC_BACKGROUND_COLOR: Self::Color = Rgb565::RED;
+    const PANIC_TEXT_COLOR: Self::Color = Rgb565::BLACK;
+}
+
+/// A fixed size image held in memory.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FrameBuffer<C> {
+    size: Size,
+    pixels: Vec<C>,
+}
+impl<C: PixelColor> FrameBuffer<C> {
+    pub fn new(size: Size, color: C) -> Self {
+        Self {
+            size,
+            pixels: vec![color; (size.width * size.height) as usize],
+        }
+    }
+
+    /// Gets the color of a pixel, which must be within the frame.
+    pub fn pixel(&self, point: Point) -> C {
+        self.pixels[self.index(point).expect("Pixel is outside the frame")]
+    }
+
+    /// Sets the color of a pixel, which must be within the frame.
+    pub fn set_pixel(&mut self, point: Point, color: C) {
+        let idx = self.index(point).expect("Pixel is outside the frame");
+        self.pixels[idx] = color;
+    }
+
+    pub fn pixels(&self) -> &[C] {
+        &self.pixels
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        (point.x >= 0
+            && point.y >= 0
+            && (point.x as u32) < self.size.width
+            && (point.y as u32) < self.size.height)
+            .then(|| point.y as usize * self.size.width as usize + point.x as usize)
+    }
+}
+impl<C> OriginDimensions for FrameBuffer<C> {
+    fn size(&self) -> Size {
+        self.size
+    }
+}
+impl<C: PixelColor> DrawTarget for FrameBuffer<C> {
+    type Color = C;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(point, color) in pixels {
+            // Like the real displays, silently clip anything off screen
+            if let Some(idx) = self.index(point) {
+                self.pixels[idx] = color;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.pixels.fill(color);
+        Ok(())
+    }
+}
+
+/// A simulated display that records a copy of the frame each time it is flushed.
+pub struct SimDisplay<P: Profile> {
+    frame: FrameBuffer<P::Color>,
+    screens: Vec<FrameBuffer<P::Color>>,
+    pixels_drawn: usize,
+}
+impl<P: Profile> SimDisplay<P> {
+    pub fn new() -> Self {
+        Self {
+            frame: FrameBuffer::new(P::SIZE, P::BACKGROUND_COLOR),
+            screens: Vec::new(),
+            pixels_drawn: 0,
+        }
+    }
+
+    /// The current contents of the display, whether flushed or not.
+    pub fn frame(&self) -> &FrameBuffer<P::Color> {
+        &self.frame
+    }
+
+    /// Every distinct screen that has been flushed, in order.
+    pub fn screens(&self) -> &[FrameBuffer<P::Color>] {
+        &self.screens
+    }
+
+    /// The number of pixels drawn so far, not counting clears.
+    pub fn pixels_drawn(&self) -> usize {
+        self.pixels_drawn
+    }
+}
+impl<P: Profile> Default for SimDisplay<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<P: Profile> OriginDimensions for SimDisplay<P> {
+    fn size(&self) -> Size {
+        self.frame.size()
+    }
+}
+impl<P: Profile> DrawTarget for SimDisplay<P> {
+    type Color = P::Color;
+    type Error = core::convert::Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.frame
+            .draw_iter(pixels.into_iter().inspect(|_| self.pixels_drawn += 1))
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.frame.clear(color)
+    }
+}
+impl<P: Profile> Display for SimDisplay<P> {
+    const FONT: mono_font::MonoFont<'static> = P::FONT;
+    const BACKGROUND_COLOR: Self::Color = P::BACKGROUND_COLOR;
+    const TEXT_COLOR: Self::Color = P::TEXT_COLOR;
+    const PANIC_BACKGROUND_COLOR: Self::Color = P::PANIC_BACKGROUND_COLOR;
+    const PANIC_TEXT_COLOR: Self::Color = P::PANIC_TEXT_COLOR;
+
+    fn flush(&mut self) {
+        // Flushing the same frame more than once is common, so only keep distinct screens
+        if self.screens.last() != Some(&self.frame) {
+            self.screens.push(self.frame.clone());
+        }
+    }
+}
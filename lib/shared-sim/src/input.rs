@@ -0,0 +1,115 @@
+use shared::{input::POLL_MS, prelude::*};
+use std::collections::VecDeque;
+use std::task::Poll;
+
+/// Input that presses a button whenever one is waited on, up to an optional limit, and follows
+/// scripts of menu navigation and of polled events.
+pub struct ScriptedInput {
+    remaining: Option<usize>,
+    presses: usize,
+    navs: VecDeque<Nav>,
+    /// Events returned by polling, each after the given number of empty polls.
+    events: VecDeque<(u32, ButtonEvent)>,
+    polls: u32,
+}
+impl ScriptedInput {
+    /// Allows exactly `presses` button presses, panicking if any more are waited on.
+    pub fn new(presses: usize) -> Self {
+        Self {
+            remaining: Some(presses),
+            presses: 0,
+            navs: VecDeque::new(),
+            events: VecDeque::new(),
+            polls: 0,
+        }
+    }
+
+    pub fn unlimited() -> Self {
+        Self {
+            remaining: None,
+            ..Self::new(0)
+        }
+    }
+
+    /// Adds menu navigation to the script, which is separate from the button presses.
+    pub fn navigate(mut self, navs: impl IntoIterator<Item = Nav>) -> Self {
+        self.navs.extend(navs);
+        self
+    }
+
+    /// Adds events to be returned by [`Input::poll_event`] and [`AsyncInput::event`], each after `after_ms` of polling
+    /// without one.
+    pub fn events(mut self, events: impl IntoIterator<Item = (u32, ButtonEvent)>) -> Self {
+        self.events
+            .extend(events.into_iter().map(|(ms, e)| (ms / POLL_MS, e)));
+        self
+    }
+
+    /// The number of button presses so far.
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    /// The number of times the input has been polled.
+    pub fn polls(&self) -> u32 {
+        self.polls
+    }
+}
+
+impl Input for ScriptedInput {
+    const NAV_HELP: &'static str = "</>: move, *: select";
+
+    fn poll_event(&mut self) -> Option<ButtonEvent> {
+        self.polls += 1;
+        let (wait, _) = self.events.front_mut()?;
+        if *wait > 0 {
+            *wait -= 1;
+            return None;
+        }
+
+        self.events.pop_front().map(|(_, e)| e)
+    }
+
+    fn pause(&mut self) {}
+
+    fn nav(button: ButtonId) -> Option<Nav> {
+        match button {
+            ButtonId::A => Some(Nav::Previous),
+            ButtonId::B => Some(Nav::Next),
+            _ => Some(Nav::Select),
+        }
+    }
+
+    fn wait_for_button(&mut self) -> ButtonId {
+        if let Some(remaining) = self.remaining.as_mut() {
+            if *remaining == 0 {
+                panic!(
+                    "Input script exhausted after {} button presses",
+                    self.presses
+                );
+            }
+            *remaining -= 1;
+        }
+
+        self.presses += 1;
+        ButtonId::A
+    }
+
+    fn wait_for_nav(&mut self) -> Nav {
+        self.navs.pop_front().expect("Navigation script exhausted")
+    }
+}
+
+/// Polls the script each time the future is polled, staying pending while there is no event.
+impl AsyncInput for ScriptedInput {
+    async fn event(&mut self) -> ButtonEvent {
+        std::future::poll_fn(|cx| match self.poll_event() {
+            Some(event) => Poll::Ready(event),
+            None => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
@@ -0,0 +1,22 @@
+//! A simulated board so that the `shared` test flows can run on the host with `cargo test`.
+//!
+//! The display is an in-memory framebuffer that captures every flushed screen, the input is
+//! scripted, and the RTC and delays are fakes driven by a virtual clock. Frames can be compared
+//! against golden images with [`snapshot::assert_snapshot`].
+pub use display::{FrameBuffer, Metro, Profile, Pygamer, SimDisplay};
+pub use input::ScriptedInput;
+pub use time::{FakeClockRtc, FakeDelay, FakeRtc, FakeRtc16, SimClock};
+
+mod critical_section;
+mod display;
+mod input;
+pub mod snapshot;
+mod time;
+
+pub mod prelude {
+    pub use super::{
+        FakeClockRtc, FakeDelay, FakeRtc, FakeRtc16, FrameBuffer, Metro, Profile, Pygamer,
+        ScriptedInput, SimClock, SimDisplay,
+    };
+    pub use shared::prelude::*;
+}
@@ -0,0 +1,244 @@
+//! Golden-image snapshot testing of framebuffers.
+//!
+//! Golden images are stored in `tests/golden` as binary PBM files for monochrome frames and
+//! binary PPM files for color frames, so they can be viewed with any image viewer. Run the tests
+//! with `UPDATE_GOLDEN=1` to write the current output as the new golden images.
+use crate::FrameBuffer;
+use embedded_graphics::{
+    pixelcolor::{BinaryColor, Rgb565, Rgb888},
+    prelude::*,
+};
+use std::{fmt::Write, fs, path::PathBuf};
+
+/// Most columns of the diff region to show in a failure message.
+const MAX_DIFF_COLUMNS: u32 = 48;
+
+/// A color that can be stored in a Netpbm golden image.
+pub trait SnapshotColor: PixelColor {
+    const EXTENSION: &'static str;
+
+    fn encode(frame: &FrameBuffer<Self>) -> Vec<u8>;
+    fn decode(data: &[u8]) -> Result<FrameBuffer<Self>, String>;
+    /// Character used to draw the pixel in a failure message.
+    fn to_char(self) -> char;
+}
+
+impl SnapshotColor for BinaryColor {
+    const EXTENSION: &'static str = "pbm";
+
+    fn encode(frame: &FrameBuffer<Self>) -> Vec<u8> {
+        let size = frame.size();
+        let mut data = format!("P4\n{} {}\n", size.width, size.height).into_bytes();
+
+        // Each row is packed into bytes MSB first with set bits for lit pixels
+        for row in frame.pixels().chunks(size.width as usize) {
+            for byte in row.chunks(8) {
+                data.push(
+                    byte.iter()
+                        .enumerate()
+                        .filter(|(_, c)| c.is_on())
+                        .fold(0, |b, (i, _)| b | (0x80 >> i)),
+                );
+            }
+        }
+
+        data
+    }
+
+    fn decode(data: &[u8]) -> Result<FrameBuffer<Self>, String> {
+        let (size, data) = parse_header(data, "P4", 2)?;
+        let row_len = size.width.div_ceil(8) as usize;
+        if data.len() != row_len * size.height as usize {
+            return Err("Wrong amount of pixel data".into());
+        }
+
+        let mut frame = FrameBuffer::new(size, BinaryColor::Off);
+        for (y, row) in data.chunks(row_len).enumerate() {
+            for x in 0..size.width {
+                if row[x as usize / 8] & (0x80 >> (x % 8)) != 0 {
+                    frame.set_pixel(Point::new(x as i32, y as i32), BinaryColor::On);
+                }
+            }
+        }
+
+        Ok(frame)
+    }
+
+    fn to_char(self) -> char {
+        if self.is_on() { '#' } else { '.' }
+    }
+}
+
+impl SnapshotColor for Rgb565 {
+    const EXTENSION: &'static str = "ppm";
+
+    fn encode(frame: &FrameBuffer<Self>) -> Vec<u8> {
+        let size = frame.size();
+        let mut data = format!("P6\n{} {}\n255\n", size.width, size.height).into_bytes();
+
+        for color in frame.pixels() {
+            let color = Rgb888::from(*color);
+            data.extend([color.r(), color.g(), color.b()]);
+        }
+
+        data
+    }
+
+    fn decode(data: &[u8]) -> Result<FrameBuffer<Self>, String> {
+        let (size, data) = parse_header(data, "P6", 3)?;
+        if data.len() != 3 * (size.width * size.height) as usize {
+            return Err("Wrong amount of pixel data".into());
+        }
+
+        let mut frame = FrameBuffer::new(size, Rgb565::BLACK);
+        for (i, rgb) in data.chunks(3).enumerate() {
+            frame.set_pixel(
+                Point::new(
+                    (i as u32 % size.width) as i32,
+                    (i as u32 / size.width) as i32,
+                ),
+                Rgb888::new(rgb[0], rgb[1], rgb[2]).into(),
+            );
+        }
+
+        Ok(frame)
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Rgb565::BLACK => '#',
+            Rgb565::WHITE => '.',
+            _ => '+',
+        }
+    }
+}
+
+/// Parses a binary Netpbm header with the given number of numeric fields, returning the image
+/// size and the pixel data.
+fn parse_header<'a>(
+    data: &'a [u8],
+    magic: &str,
+    num_fields: usize,
+) -> Result<(Size, &'a [u8]), String> {
+    let mut fields = Vec::new();
+    let mut rest = data
+        .strip_prefix(magic.as_bytes())
+        .ok_or_else(|| format!("Not a `{magic}` image"))?;
+
+    while fields.len() < num_fields {
+        rest = rest.trim_ascii_start();
+        if rest.first() == Some(&b'#') {
+            let end = rest.iter().position(|b| *b == b'\n').unwrap_or(rest.len());
+            rest = &rest[end..];
+            continue;
+        }
+
+        let end = rest
+            .iter()
+            .position(|b| b.is_ascii_whitespace())
+            .ok_or("Truncated header")?;
+        let field = std::str::from_utf8(&rest[..end])
+            .ok()
+            .and_then(|s| s.parse::<u32>().ok())
+            .ok_or("Invalid header field")?;
+        fields.push(field);
+        rest = &rest[end..];
+    }
+
+    // Exactly one whitespace character separates the header from the data
+    Ok((Size::new(fields[0], fields[1]), &rest[1..]))
+}
+
+fn golden_path(name: &str, extension: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{name}.{extension}"))
+}
+
+/// Compares a frame against its golden image, panicking with a readable diff if they differ.
+pub fn assert_snapshot<C: SnapshotColor>(name: &str, frame: &FrameBuffer<C>) {
+    let path = golden_path(name, C::EXTENSION);
+
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, C::encode(frame)).unwrap();
+        return;
+    }
+
+    let golden = match fs::read(&path) {
+        Ok(data) => C::decode(&data)
+            .unwrap_or_else(|e| panic!("Could not decode `{}`: {e}", path.display())),
+        Err(e) => panic!(
+            "Could not read `{}` ({e}), rerun with `UPDATE_GOLDEN=1` to create it",
+            path.display()
+        ),
+    };
+
+    if let Some(diff) = diff(&golden, frame) {
+        panic!(
+            "Snapshot `{name}` does not match `{}`, rerun with `UPDATE_GOLDEN=1` if the change \
+            is expected.\n{diff}",
+            path.display()
+        );
+    }
+}
+
+/// Describes the differences between two frames, if there are any.
+fn diff<C: SnapshotColor>(expected: &FrameBuffer<C>, actual: &FrameBuffer<C>) -> Option<String> {
+    if expected.size() != actual.size() {
+        return Some(format!(
+            "Expected a {}x{} frame but got {}x{}",
+            expected.size().width,
+            expected.size().height,
+            actual.size().width,
+            actual.size().height
+        ));
+    }
+
+    let size = actual.size();
+    let differing: Vec<_> = (0..size.height as i32)
+        .flat_map(|y| (0..size.width as i32).map(move |x| Point::new(x, y)))
+        .filter(|p| expected.pixel(*p) != actual.pixel(*p))
+        .collect();
+    let first = *differing.first()?;
+
+    // Bounding box of the differences
+    let (min, max) = differing.iter().fold((first, first), |(min, max), p| {
+        (min.component_min(*p), max.component_max(*p))
+    });
+    let max_x = max.x.min(min.x + MAX_DIFF_COLUMNS as i32 - 1);
+
+    let mut out = format!(
+        "{} pixels differ within ({}, {})-({}, {}), shown as expected | actual | diff:\n",
+        differing.len(),
+        min.x,
+        min.y,
+        max.x,
+        max.y
+    );
+    for y in min.y..=max.y {
+        let row = |frame: &FrameBuffer<C>| -> String {
+            (min.x..=max_x)
+                .map(|x| frame.pixel(Point::new(x, y)).to_char())
+                .collect()
+        };
+        let marks: String = (min.x..=max_x)
+            .map(|x| {
+                let p = Point::new(x, y);
+                if expected.pixel(p) == actual.pixel(p) {
+                    ' '
+                } else {
+                    'X'
+                }
+            })
+            .collect();
+
+        writeln!(out, "{y:4} {} | {} | {marks}", row(expected), row(actual)).unwrap();
+    }
+    if max_x < max.x {
+        writeln!(out, "(only the first {MAX_DIFF_COLUMNS} columns are shown)").unwrap();
+    }
+
+    Some(out)
+}
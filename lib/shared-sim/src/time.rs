@@ -0,0 +1,504 @@
+use embedded_hal::delay::DelayNs;
+use fugit::SecsDurationU32;
+use shared::{
+    executor::{self, TimeSource},
+    rtc::{
+        AlarmMask, Datetime, NoBackupRegister, RtcAlarm, RtcBackup, RtcClock, RtcCompare,
+        RtcCount16, RtcCount32, RtcFlag, RtcInterrupt, RtcInterrupts,
+    },
+    tests::Stopwatch,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    task::Waker,
+};
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+const NANOS_PER_MS: u64 = 1_000_000;
+
+#[derive(Default)]
+struct SimTime {
+    now_ns: Cell<u64>,
+    /// The earliest deadline in milliseconds that the executor is waiting for.
+    alarm: RefCell<Option<(u32, Waker)>>,
+}
+
+/// Virtual time that is only advanced by the fakes, shared between all clones.
+///
+/// As a [`TimeSource`], time stands still while futures are being polled and jumps to the next
+/// deadline whenever the executor would sleep, as if the alarm interrupt had come.
+#[derive(Clone, Default)]
+pub struct SimClock(Rc<SimTime>);
+impl SimClock {
+    /// Virtual time in nanoseconds.
+    pub fn now_ns(&self) -> u64 {
+        self.0.now_ns.get()
+    }
+
+    pub fn advance_ns(&self, nanos: u64) {
+        self.0.now_ns.set(self.0.now_ns.get() + nanos);
+    }
+
+    /// Moves time on to the alarm, if there is one, and wakes the executor.
+    fn ring_alarm(&self) {
+        let Some((deadline_ms, waker)) = self.0.alarm.take() else {
+            return;
+        };
+
+        let deadline_ns = u64::from(deadline_ms) * NANOS_PER_MS;
+        self.0.now_ns.set(self.now_ns().max(deadline_ns));
+        waker.wake();
+    }
+}
+
+thread_local! {
+    /// The clock that the executor is waiting for on this thread.
+    static WAITING_ON: RefCell<Option<SimClock>> = const { RefCell::new(None) };
+}
+
+impl TimeSource for SimClock {
+    fn now_ms(&self) -> u32 {
+        (self.now_ns() / NANOS_PER_MS) as u32
+    }
+
+    fn wake_at(&self, deadline_ms: u32, waker: &Waker) {
+        let mut alarm = self.0.alarm.borrow_mut();
+        if alarm
+            .as_ref()
+            .is_none_or(|(earliest, _)| deadline_ms < *earliest)
+        {
+            *alarm = Some((deadline_ms, waker.clone()));
+        }
+
+        WAITING_ON.set(Some(self.clone()));
+        executor::set_idle_hook(|| {
+            if let Some(clock) = WAITING_ON.take() {
+                clock.ring_alarm();
+            }
+        });
+    }
+}
+
+/// Times delays in virtual nanoseconds.
+impl Stopwatch for SimClock {
+    fn rate_hz(&self) -> u32 {
+        NANOS_PER_SEC as u32
+    }
+
+    fn ticks(&mut self) -> u32 {
+        self.now_ns() as u32
+    }
+}
+
+/// A delay that just advances virtual time.
+pub struct FakeDelay {
+    clock: SimClock,
+}
+impl FakeDelay {
+    pub fn new(clock: SimClock) -> Self {
+        Self { clock }
+    }
+}
+impl DelayNs for FakeDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.clock.advance_ns(ns.into());
+    }
+}
+
+/// A fake RTC in 32-bit counter mode that counts virtual time.
+///
+/// Because the flows busy-wait on the counter, every register read advances virtual time by
+/// [`FakeRtc::READ_NS`], as a read on real hardware would.
+pub struct FakeRtc {
+    clock: SimClock,
+    rate_hz: u32,
+    prescaler: u32,
+    base_count: u32,
+    base_ns: u64,
+    /// Period and next deadline of the running countdown in virtual nanoseconds.
+    countdown: Option<(u64, u64)>,
+    /// The compare value, until it has been reached.
+    compare: Option<u32>,
+    /// Period and next deadline of the periodic interrupt in virtual nanoseconds.
+    periodic: Option<(u64, u64)>,
+}
+impl FakeRtc {
+    pub const READ_NS: u64 = 50_000;
+
+    pub fn new(clock: SimClock, rate_hz: u32) -> Self {
+        let base_ns = clock.now_ns();
+
+        Self {
+            clock,
+            rate_hz,
+            prescaler: 1,
+            base_count: 0,
+            base_ns,
+            countdown: None,
+            compare: None,
+            periodic: None,
+        }
+    }
+
+    pub fn prescaler(&self) -> u32 {
+        self.prescaler
+    }
+
+    fn read(&self) -> u64 {
+        self.clock.advance_ns(Self::READ_NS);
+        self.clock.now_ns()
+    }
+
+    fn ticks_since_base(&self, now_ns: u64) -> u64 {
+        (now_ns - self.base_ns) * u64::from(self.rate_hz)
+            / (u64::from(self.prescaler) * NANOS_PER_SEC)
+    }
+}
+impl DelayNs for FakeRtc {
+    fn delay_ns(&mut self, ns: u32) {
+        self.clock.advance_ns(ns.into());
+    }
+}
+impl RtcCount32 for FakeRtc {
+    type ClockMode = FakeClockRtc;
+    type Count16Mode = FakeRtc16;
+
+    fn count32(&self) -> u32 {
+        let now_ns = self.read();
+        self.base_count
+            .wrapping_add(self.ticks_since_base(now_ns) as u32)
+    }
+
+    fn set_count32(&mut self, count: u32) {
+        self.base_count = count;
+        self.base_ns = self.clock.now_ns();
+    }
+
+    fn reset_and_compute_prescaler(&mut self, timeout: SecsDurationU32) {
+        // Use the smallest prescaler for which the timeout fits in 16 bits of counts
+        let ticks = u64::from(timeout.to_secs()) * u64::from(self.rate_hz);
+        self.prescaler = (ticks / u64::from(u16::MAX) + 1)
+            .next_power_of_two()
+            .min(1024) as u32;
+        self.set_count32(0);
+    }
+
+    fn start_countdown(&mut self, timeout: SecsDurationU32) {
+        let period = u64::from(timeout.to_secs()) * NANOS_PER_SEC;
+        self.countdown = Some((period, self.clock.now_ns() + period));
+    }
+
+    fn wait_countdown(&mut self) -> nb::Result<(), core::convert::Infallible> {
+        let now_ns = self.read();
+        let (period, deadline) = self
+            .countdown
+            .as_mut()
+            .expect("Countdown was never started");
+
+        if now_ns >= *deadline {
+            *deadline += *period;
+            Ok(())
+        } else {
+            Err(nb::Error::WouldBlock)
+        }
+    }
+
+    fn into_count16_mode(self) -> Self::Count16Mode {
+        let base_ns = self.clock.now_ns();
+
+        FakeRtc16 {
+            rtc: self,
+            period: u16::MAX,
+            base_count: 0,
+            base_ns,
+            compares: [0; 2],
+            flags_checked: 0,
+        }
+    }
+
+    fn into_clock_mode(self) -> Self::ClockMode {
+        FakeClockRtc::new(
+            self.clock,
+            Datetime {
+                day: 1,
+                month: 1,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+/// A fake RTC in 16-bit counter mode, without a prescaler.
+pub struct FakeRtc16 {
+    rtc: FakeRtc,
+    period: u16,
+    base_count: u16,
+    base_ns: u64,
+    compares: [u16; 2],
+    /// The ticks since the base up to which the flags have been checked.
+    flags_checked: u64,
+}
+impl FakeRtc16 {
+    fn ticks_since_base(&self) -> u64 {
+        (self.rtc.clock.now_ns() - self.base_ns) * u64::from(self.rtc.rate_hz) / NANOS_PER_SEC
+    }
+
+    /// The count `ticks` after the base.
+    fn count_at(&self, ticks: u64) -> u16 {
+        ((u64::from(self.base_count) + ticks) % (u64::from(self.period) + 1)) as u16
+    }
+
+    /// Counts on from the current count.
+    fn rebase(&mut self, count: u16) {
+        self.base_count = count;
+        self.base_ns = self.rtc.clock.now_ns();
+        self.flags_checked = 0;
+    }
+}
+impl RtcCount16 for FakeRtc16 {
+    type Count32Mode = FakeRtc;
+
+    fn count16(&self) -> u16 {
+        self.rtc.read();
+        self.count_at(self.ticks_since_base())
+    }
+
+    fn set_count16(&mut self, count: u16) {
+        self.rebase(count);
+    }
+
+    fn set_period(&mut self, period: u16) {
+        let count = self.count_at(self.ticks_since_base());
+        self.period = period;
+        self.rebase(count.min(period));
+    }
+
+    fn set_compare16(&mut self, n: usize, count: u16) {
+        self.compares[n] = count;
+    }
+
+    /// Reports the flags for every tick since they were last taken.
+    fn take_flags(&mut self) -> u8 {
+        let ticks = self.ticks_since_base();
+        let mut flags = 0;
+        for count in (self.flags_checked + 1..=ticks).map(|t| self.count_at(t)) {
+            if count == self.compares[0] {
+                flags |= RtcFlag::Match.mask();
+            }
+            if count == self.compares[1] {
+                flags |= RtcFlag::Match1.mask();
+            }
+            if count == 0 {
+                flags |= RtcFlag::Overflow.mask();
+            }
+        }
+        self.flags_checked = ticks;
+        flags
+    }
+
+    fn into_count32_mode(mut self) -> Self::Count32Mode {
+        let count = self.count16();
+        self.rtc.prescaler = 1;
+        self.rtc.set_count32(count.into());
+        self.rtc
+    }
+}
+
+/// Reports what the interrupts would have seen by now, without taking the time of a read.
+impl RtcInterrupts for FakeRtc {
+    fn take_interrupts(&mut self) -> Option<RtcInterrupt> {
+        let now_ns = self.clock.now_ns();
+        let mut seen = RtcInterrupt::default();
+
+        let count = self
+            .base_count
+            .wrapping_add(self.ticks_since_base(now_ns) as u32);
+        if let Some(compare) = self.compare
+            && count.wrapping_sub(compare) < 1 << 31
+        {
+            self.compare = None;
+            seen.flags |= RtcFlag::Match.mask();
+            seen.times += 1;
+        }
+
+        if let Some((period, next)) = self.periodic.as_mut()
+            && now_ns >= *next
+        {
+            let times = (now_ns - *next) / *period + 1;
+            *next += times * *period;
+            seen.flags |= RtcFlag::Periodic.mask();
+            seen.times += times as u32;
+        }
+
+        (seen.times > 0).then_some(seen)
+    }
+}
+impl RtcCompare for FakeRtc {
+    const HAS_PERIODIC: bool = true;
+
+    fn set_compare(&mut self, count: u32) {
+        self.compare = Some(count);
+    }
+
+    fn disable_compare(&mut self) {
+        self.compare = None;
+    }
+
+    /// The intervals are in step with virtual time rather than with the count, like the
+    /// prescaler stages they come from.
+    fn enable_periodic(&mut self, n: u8) {
+        let period = (1 << (n + 3)) * NANOS_PER_SEC / u64::from(self.rate_hz);
+        let now_ns = self.clock.now_ns();
+        self.periodic = Some((period, (now_ns / period + 1) * period));
+    }
+
+    fn disable_periodic(&mut self) {
+        self.periodic = None;
+    }
+}
+
+/// A fake RTC in clock mode that follows the calendar rules of the hardware.
+pub struct FakeClockRtc {
+    clock: SimClock,
+    base: Datetime,
+    base_ns: u64,
+    alarm: Option<(Datetime, AlarmMask)>,
+    /// The seconds since the base up to which the alarm has been checked.
+    alarm_checked: u64,
+    backup: [u32; Self::BACKUP_REGISTERS],
+    tamper: Option<Datetime>,
+}
+impl FakeClockRtc {
+    pub fn new(clock: SimClock, time: Datetime) -> Self {
+        let base_ns = clock.now_ns();
+
+        Self {
+            clock,
+            base: time,
+            base_ns,
+            alarm: None,
+            alarm_checked: 0,
+            backup: [0; Self::BACKUP_REGISTERS],
+            tamper: None,
+        }
+    }
+
+    fn elapsed_secs(&self) -> u64 {
+        (self.clock.now_ns() - self.base_ns) / NANOS_PER_SEC
+    }
+
+    fn time_at(&self, elapsed_secs: u64) -> Datetime {
+        (0..elapsed_secs).fold(self.base, |time, _| next_second(time))
+    }
+}
+impl RtcClock for FakeClockRtc {
+    fn current_time(&self) -> Datetime {
+        self.clock.advance_ns(FakeRtc::READ_NS);
+        self.time_at(self.elapsed_secs())
+    }
+
+    fn set_time(&mut self, time: Datetime) {
+        self.base = time;
+        self.base_ns = self.clock.now_ns();
+        self.alarm_checked = 0;
+    }
+}
+/// Reports the alarm for every second that matched since it was last asked.
+impl RtcInterrupts for FakeClockRtc {
+    fn take_interrupts(&mut self) -> Option<RtcInterrupt> {
+        let (alarm, mask) = self.alarm?;
+        let elapsed = self.elapsed_secs();
+        let times = (self.alarm_checked + 1..=elapsed)
+            .filter(|&secs| alarm_matches(alarm, mask, self.time_at(secs)))
+            .count() as u32;
+        self.alarm_checked = elapsed;
+
+        (times > 0).then_some(RtcInterrupt {
+            flags: RtcFlag::Match.mask(),
+            times,
+        })
+    }
+}
+impl RtcAlarm for FakeClockRtc {
+    fn set_alarm(&mut self, time: Datetime, mask: AlarmMask) {
+        self.alarm = Some((time, mask));
+        self.alarm_checked = self.elapsed_secs();
+    }
+
+    fn disable_alarm(&mut self) {
+        self.alarm = None;
+    }
+}
+
+/// Keeps backup registers and a tamper timestamp like the SAMD51, which a reset of the
+/// simulation leaves alone, as it does the time.
+impl RtcBackup for FakeClockRtc {
+    const BACKUP_REGISTERS: usize = 4;
+    const HAS_TAMPER: bool = true;
+
+    fn backup(&self, n: usize) -> Option<u32> {
+        self.backup.get(n).copied()
+    }
+
+    fn set_backup(&mut self, n: usize, value: u32) -> Result<(), NoBackupRegister> {
+        *self.backup.get_mut(n).ok_or(NoBackupRegister)? = value;
+        Ok(())
+    }
+
+    fn trigger_tamper(&mut self) {
+        self.tamper = Some(self.current_time());
+    }
+
+    fn take_tamper_timestamp(&mut self) -> Option<Datetime> {
+        self.tamper.take()
+    }
+}
+
+/// Whether the fields of the time picked by the mask match the alarm.
+fn alarm_matches(alarm: Datetime, mask: AlarmMask, time: Datetime) -> bool {
+    let fields = |t: Datetime| [t.seconds, t.minutes, t.hours, t.day];
+    let compared = mask as usize;
+    fields(alarm)[..compared] == fields(time)[..compared]
+}
+
+/// Advances the time by one second.
+///
+/// The hardware treats every year divisible by four as a leap year, and its six bit year
+/// field wraps from 63 back to 0.
+fn next_second(mut time: Datetime) -> Datetime {
+    let days_in_month = match time.month {
+        2 if time.year.is_multiple_of(4) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+
+    time.seconds += 1;
+    if time.seconds < 60 {
+        return time;
+    }
+    time.seconds = 0;
+    time.minutes += 1;
+    if time.minutes < 60 {
+        return time;
+    }
+    time.minutes = 0;
+    time.hours += 1;
+    if time.hours < 24 {
+        return time;
+    }
+    time.hours = 0;
+    time.day += 1;
+    if time.day <= days_in_month {
+        return time;
+    }
+    time.day = 1;
+    time.month += 1;
+    if time.month <= 12 {
+        return time;
+    }
+    time.month = 1;
+    time.year = (time.year + 1) % 64;
+    time
+}
@@ -0,0 +1,114 @@
+//! Checks carry on after failures, showing them on the screen and tallying them at the end.
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+
+fn screens<P: Profile>(presses: usize) -> ScreensGen<SimDisplay<P>, ScriptedInput> {
+    ScreensGen::new(SimDisplay::new(), ScriptedInput::new(presses))
+}
+
+#[test]
+fn passing_checks_continue_silently() {
+    let mut screens = screens::<Metro>(0);
+    screens.start_test("passing");
+
+    let value = 0x2Au8;
+    assert!(check!(screens, value > 3));
+    assert!(check_eq!(screens, value, 42));
+    assert!(check_range!(screens, value, 40..=45));
+    assert!(check_eq!(screens, [1u8, 2, 3], [1, 2, 3]));
+
+    assert_eq!(screens.check_log().passed(), 4);
+    assert_eq!(screens.check_log().failed(), 0);
+    // Nothing waited for a button
+    let (display, _) = screens.free();
+    assert!(display.screens().is_empty());
+}
+
+#[test]
+fn failing_checks_wait_and_continue() {
+    let mut screens = screens::<Metro>(3);
+    screens.start_test("failing");
+
+    let crc = 0xE8C27688u32;
+    assert!(!check_eq!(screens, crc, 0xE8C27689u32));
+    assert!(check!(screens, crc != 0));
+    assert!(!check_range!(screens, crc, ..0x1000));
+    assert!(!check!(screens, crc == 0));
+
+    let log = screens.check_log();
+    assert_eq!((log.passed(), log.failed()), (1, 3));
+    let lines: Vec<_> = log.failures().map(|l| l.expr).collect();
+    assert_eq!(
+        lines,
+        ["crc == 0xE8C27689u32", "crc in ..0x1000", "crc == 0"]
+    );
+    let (_, input) = screens.free();
+    assert_eq!(input.presses(), 3);
+}
+
+#[test]
+fn starting_a_test_clears_the_log() {
+    let mut screens = screens::<Metro>(1);
+    screens.start_test("first");
+    check_eq!(screens, 1, 2);
+
+    screens.start_test("second");
+    assert_eq!(screens.check_log().passed(), 0);
+    assert_eq!(screens.check_log().failed(), 0);
+}
+
+fn failure_screen<P: Profile>(name: &str) {
+    let mut screens = screens::<P>(1);
+    screens.start_test("dsu");
+    let flash_crc = 0xE8C27688u32;
+    check_eq!(screens, flash_crc, 0xE8C27689u32);
+
+    let (display, _) = screens.free();
+    assert_snapshot(name, &display.screens()[0]);
+}
+
+#[test]
+fn failure_screen_metro() {
+    failure_screen::<Metro>("check_failure_metro");
+}
+
+#[test]
+fn failure_screen_pygamer() {
+    failure_screen::<Pygamer>("check_failure_pygamer");
+}
+
+fn tally_screen<P: Profile>(name: &str) {
+    let mut screens = screens::<P>(2);
+    screens.start_test("aes");
+    check!(screens, true);
+    check_eq!(screens, [0u8, 1], [0, 2]);
+    check_range!(screens, 7, 0..5);
+
+    screens.show_test_complete();
+    let (display, _) = screens.free();
+    assert_snapshot(name, display.frame());
+}
+
+#[test]
+fn tally_screen_metro() {
+    tally_screen::<Metro>("check_tally_metro");
+}
+
+#[test]
+fn tally_screen_pygamer() {
+    tally_screen::<Pygamer>("check_tally_pygamer");
+}
+
+#[test]
+fn named_checks_keep_their_expression() {
+    let mut screens = screens::<Metro>(1);
+    screens.start_test("named");
+
+    for (name, value) in [("first", 1u32), ("second", 2)] {
+        check_eq!(screens, name = name, value, 1);
+    }
+
+    let log = screens.check_log();
+    assert_eq!((log.passed(), log.failed()), (1, 1));
+    let lines: Vec<_> = log.failures().map(|l| l.expr).collect();
+    assert_eq!(lines, ["value == 1"]);
+}
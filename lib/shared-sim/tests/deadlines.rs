@@ -0,0 +1,78 @@
+//! Checks the tracking of wakeups that the async stress test reports.
+use shared::deadlines::{Deadlines, MAX_TRACKED_PERIOD_US, SLOT_US};
+
+const PERIOD_US: u64 = 1_000_000;
+
+#[test]
+fn wakeups_on_time_are_not_missed() {
+    let mut deadlines = Deadlines::new();
+    let mut now_us = 0;
+    for lateness_us in [0, 1_000, 5_000, 2_000] {
+        let sleep = deadlines.sleep(now_us, PERIOD_US);
+        now_us = sleep.expected_us() + lateness_us;
+        assert_eq!(deadlines.wake(sleep, now_us), lateness_us);
+    }
+
+    assert_eq!(deadlines.wakeups(), 4);
+    assert_eq!(deadlines.misses(), 0);
+    assert_eq!(deadlines.overdue(now_us), 0);
+    assert_eq!(deadlines.worst_lateness_us(), 5_000);
+}
+
+#[test]
+fn wakeups_a_period_late_are_missed() {
+    let mut deadlines = Deadlines::new();
+    let sleep = deadlines.sleep(0, PERIOD_US);
+    assert_eq!(deadlines.wake(sleep, 2 * PERIOD_US - 1), PERIOD_US - 1);
+    assert_eq!(deadlines.misses(), 0);
+
+    let sleep = deadlines.sleep(3 * PERIOD_US, PERIOD_US);
+    assert_eq!(deadlines.wake(sleep, 5 * PERIOD_US), PERIOD_US);
+    assert_eq!(deadlines.misses(), 1);
+    assert_eq!(deadlines.worst_lateness_us(), PERIOD_US);
+
+    // Early wakeups are not late at all
+    let sleep = deadlines.sleep(6 * PERIOD_US, PERIOD_US);
+    assert_eq!(deadlines.wake(sleep, 6 * PERIOD_US), 0);
+}
+
+#[test]
+fn tasks_that_never_wake_are_overdue() {
+    let mut deadlines = Deadlines::new();
+    let dropped = deadlines.sleep(0, PERIOD_US);
+    let mut now_us = 0;
+
+    // A second task keeps waking while the first sleeps on
+    while now_us < 2 * PERIOD_US {
+        let sleep = deadlines.sleep(now_us, PERIOD_US / 4);
+        now_us = sleep.expected_us();
+        deadlines.wake(sleep, now_us);
+    }
+    assert_eq!(deadlines.overdue(now_us), 0);
+    assert_eq!(deadlines.overdue(2 * PERIOD_US + SLOT_US), 1);
+
+    // Long after, its slot is reused and it stays counted
+    while now_us < 60 * PERIOD_US {
+        let sleep = deadlines.sleep(now_us, PERIOD_US / 4);
+        now_us = sleep.expected_us();
+        deadlines.wake(sleep, now_us);
+    }
+    assert_eq!(deadlines.overdue(now_us), 1);
+    assert_eq!(deadlines.misses(), 0);
+
+    // Waking at last does not count it a second time
+    deadlines.wake(dropped, now_us);
+    assert_eq!(deadlines.overdue(now_us), 1);
+    assert_eq!(deadlines.misses(), 0);
+}
+
+#[test]
+fn long_periods_are_only_checked_on_waking() {
+    let mut deadlines = Deadlines::new();
+    let period_us = MAX_TRACKED_PERIOD_US + SLOT_US;
+    let sleep = deadlines.sleep(0, period_us);
+    assert_eq!(deadlines.overdue(10 * period_us), 0);
+
+    deadlines.wake(sleep, 3 * period_us);
+    assert_eq!(deadlines.misses(), 1);
+}
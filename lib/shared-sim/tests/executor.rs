@@ -0,0 +1,203 @@
+//! Counting how futures use the waker of `block_on`, and running several at once against virtual
+//! time.
+use shared::tests::AsyncTimer;
+use shared_sim::prelude::*;
+use std::sync::Mutex;
+use std::task::Poll;
+
+/// The executor has a single wake flag, so the tests must not block on futures at the same time.
+static EXECUTOR: Mutex<()> = Mutex::new(());
+
+/// Blocks on a future that is pending once for each step, calling the waker first if the step
+/// says so.
+fn run_steps(wakes: &[bool]) -> WakeStats {
+    let _guard = EXECUTOR.lock().unwrap();
+    let mut steps = wakes.iter();
+    let future = std::future::poll_fn(move |cx| match steps.next() {
+        Some(&wake) => {
+            if wake {
+                cx.waker().wake_by_ref();
+            }
+            Poll::Pending
+        }
+        None => Poll::Ready(()),
+    });
+
+    block_on_with_stats(future).1
+}
+
+#[test]
+fn ready_at_once() {
+    assert_eq!(
+        run_steps(&[]),
+        WakeStats {
+            polls: 1,
+            ..WakeStats::default()
+        }
+    );
+}
+
+#[test]
+fn woken_once() {
+    assert_eq!(
+        run_steps(&[true]),
+        WakeStats {
+            polls: 2,
+            wakes: 1,
+            ..WakeStats::default()
+        }
+    );
+}
+
+#[test]
+fn counts_spurious_wakeups() {
+    assert_eq!(
+        run_steps(&[true, true, true]),
+        WakeStats {
+            polls: 4,
+            wakes: 3,
+            spurious: 2,
+            missed: 0,
+        }
+    );
+}
+
+#[test]
+fn counts_missed_wakeups() {
+    // Without interrupts the executor polls again straight away, which finds the future ready
+    assert_eq!(
+        run_steps(&[false]),
+        WakeStats {
+            polls: 2,
+            wakes: 0,
+            spurious: 0,
+            missed: 1,
+        }
+    );
+}
+
+#[test]
+fn waker_outlives_block_on() {
+    let guard = EXECUTOR.lock().unwrap();
+    let mut stored = None;
+    block_on(std::future::poll_fn(|cx| {
+        stored = Some(cx.waker().clone());
+        Poll::Ready(())
+    }));
+
+    // A driver that wakes the last waker it was given later on sets the flag, which is cleared
+    // before the next future is polled
+    stored.unwrap().wake();
+    drop(guard);
+    assert_eq!(run_steps(&[false]).missed, 1);
+}
+
+/// Blocks on the future, returning its output and how many virtual milliseconds it took.
+fn timed<F: Future>(clock: &SimClock, future: F) -> (F::Output, u32) {
+    let _guard = EXECUTOR.lock().unwrap();
+    let start = clock.now_ms();
+    let output = block_on(future);
+    (output, clock.now_ms() - start)
+}
+
+#[test]
+fn join_waits_for_all() {
+    let clock = SimClock::default();
+    let (output, ms) = timed(
+        &clock,
+        join!(
+            async {
+                sleep(&clock, 30).await;
+                'a'
+            },
+            sleep(&clock, 10),
+            async { 3 },
+        ),
+    );
+
+    assert_eq!(output, ('a', (), 3));
+    assert_eq!(ms, 30);
+}
+
+#[test]
+fn select_takes_the_first() {
+    let clock = SimClock::default();
+    let (output, ms) = timed(
+        &clock,
+        select!(sleep(&clock, 30), sleep(&clock, 20), sleep(&clock, 10)),
+    );
+
+    assert_eq!(output, Either::Second(Either::Second(())));
+    assert_eq!(ms, 10);
+}
+
+#[test]
+fn timeouts() {
+    let clock = SimClock::default();
+    let never = std::future::pending::<()>();
+
+    assert_eq!(
+        timed(&clock, with_timeout(&clock, 25, never)),
+        (Err(Timeout), 25)
+    );
+    assert_eq!(
+        timed(&clock, with_timeout(&clock, 25, sleep(&clock, 5))),
+        (Ok(()), 5)
+    );
+}
+
+#[test]
+fn executor_flow() {
+    let clock = SimClock::default();
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(1));
+    {
+        let _guard = EXECUTOR.lock().unwrap();
+        screens.run_executor_test(&clock);
+    }
+
+    let log = screens.check_log();
+    assert_eq!((log.passed(), log.failed()), (8, 0));
+}
+
+/// A timer whose delays wake the executor once they are over, or never if it is `lazy`.
+struct SimTimer {
+    clock: SimClock,
+    lazy: bool,
+}
+impl AsyncTimer for SimTimer {
+    async fn delay_ms(&mut self, ms: u32) {
+        if self.lazy {
+            let mut polled = false;
+            std::future::poll_fn(|_| {
+                if std::mem::replace(&mut polled, true) {
+                    Poll::Ready(())
+                } else {
+                    Poll::Pending
+                }
+            })
+            .await;
+        } else {
+            sleep(&self.clock, ms).await;
+        }
+    }
+}
+
+#[test]
+fn timer_wakeups() {
+    let clock = SimClock::default();
+    // Each delay that is not woken fails both of its checks
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(6));
+    {
+        let _guard = EXECUTOR.lock().unwrap();
+        for lazy in [false, true] {
+            let mut timer = SimTimer {
+                clock: clock.clone(),
+                lazy,
+            };
+            screens.check_timer_wakeups(&mut timer);
+        }
+    }
+
+    let log = screens.check_log();
+    assert_eq!((log.passed(), log.failed()), (6, 6));
+}
@@ -0,0 +1,343 @@
+//! Runs the `shared` test flows to completion against the simulated boards.
+use embedded_hal::delay::DelayNs;
+use fugit::SecsDurationU32;
+use shared::{
+    retained::Retained,
+    rtc::{
+        Datetime, InvalidDatetime, RtcClock, RtcCompare, RtcCount16, RtcCount32, RtcInterrupt,
+        RtcInterrupts,
+    },
+    tests::Stopwatch,
+};
+use shared_sim::prelude::*;
+
+fn rtc_flow<P: Profile>(rtc_hz: u32) {
+    let clock = SimClock::default();
+    let mut screens = ScreensGen::new(SimDisplay::<P>::new(), ScriptedInput::new(11));
+
+    let rtc = screens.run_rtc_test(FakeRtc::new(clock.clone(), rtc_hz), rtc_hz);
+    // Two checks of each set of count samples and one more of the set count, the compare and
+    // periodic interrupts, the samples and flags of the 16-bit counter, then the calendar
+    // rollovers and the alarms, with the invalid dates skipped
+    assert_eq!(screens.check_log().passed(), 7 + 5 + 6 + 19 + 8);
+    assert_eq!(screens.check_log().failed(), 0);
+    let (display, input) = screens.free();
+
+    assert_eq!(input.presses(), 11);
+    assert!(display.screens().len() >= input.presses());
+    // The clock runs on from where it was set for the last alarm
+    let time = rtc.current_time();
+    assert_eq!(
+        (time.year, time.month, time.day, time.hours, time.minutes),
+        (25, 6, 15, 10, 20),
+        "{time:?}"
+    );
+}
+
+#[test]
+fn rtc_flow_metro() {
+    rtc_flow::<Metro>(1024);
+}
+
+#[test]
+fn rtc_flow_pygamer() {
+    rtc_flow::<Pygamer>(32768);
+}
+
+/// An RTC that ignores the count it is set to.
+struct StuckRtc(FakeRtc);
+impl DelayNs for StuckRtc {
+    fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_ns(ns);
+    }
+}
+impl RtcCount32 for StuckRtc {
+    type ClockMode = FakeClockRtc;
+    type Count16Mode = StuckRtc16;
+
+    fn count32(&self) -> u32 {
+        self.0.count32()
+    }
+
+    fn set_count32(&mut self, _count: u32) {}
+
+    fn reset_and_compute_prescaler(&mut self, timeout: SecsDurationU32) {
+        self.0.reset_and_compute_prescaler(timeout);
+    }
+
+    fn start_countdown(&mut self, timeout: SecsDurationU32) {
+        self.0.start_countdown(timeout);
+    }
+
+    fn wait_countdown(&mut self) -> nb::Result<(), core::convert::Infallible> {
+        self.0.wait_countdown()
+    }
+
+    fn into_clock_mode(self) -> Self::ClockMode {
+        self.0.into_clock_mode()
+    }
+
+    fn into_count16_mode(self) -> Self::Count16Mode {
+        StuckRtc16(self.0.into_count16_mode())
+    }
+}
+
+/// The 16-bit counter mode of the [`StuckRtc`], which works as it should.
+struct StuckRtc16(FakeRtc16);
+impl RtcCount16 for StuckRtc16 {
+    type Count32Mode = StuckRtc;
+
+    fn count16(&self) -> u16 {
+        self.0.count16()
+    }
+
+    fn set_count16(&mut self, count: u16) {
+        self.0.set_count16(count);
+    }
+
+    fn set_period(&mut self, period: u16) {
+        self.0.set_period(period);
+    }
+
+    fn set_compare16(&mut self, n: usize, count: u16) {
+        self.0.set_compare16(n, count);
+    }
+
+    fn take_flags(&mut self) -> u8 {
+        self.0.take_flags()
+    }
+
+    fn into_count32_mode(self) -> Self::Count32Mode {
+        StuckRtc(self.0.into_count32_mode())
+    }
+}
+
+impl RtcInterrupts for StuckRtc {
+    fn take_interrupts(&mut self) -> Option<RtcInterrupt> {
+        self.0.take_interrupts()
+    }
+}
+impl RtcCompare for StuckRtc {
+    const HAS_PERIODIC: bool = FakeRtc::HAS_PERIODIC;
+
+    fn set_compare(&mut self, count: u32) {
+        self.0.set_compare(count);
+    }
+
+    fn disable_compare(&mut self) {
+        self.0.disable_compare();
+    }
+
+    fn enable_periodic(&mut self, n: u8) {
+        self.0.enable_periodic(n);
+    }
+
+    fn disable_periodic(&mut self) {
+        self.0.disable_periodic();
+    }
+}
+
+#[test]
+fn rtc_flow_catches_ignored_set_count() {
+    let clock = SimClock::default();
+    // The failure screen takes one more press
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(12));
+
+    screens.run_rtc_test(StuckRtc(FakeRtc::new(clock.clone(), 1024)), 1024);
+
+    assert_eq!(screens.check_log().failed(), 1);
+    assert_eq!(screens.check_log().passed(), 6 + 5 + 6 + 19 + 8);
+    let failure = screens.check_log().failures().next().unwrap();
+    assert_eq!(failure.expr, "first in set_count..=set_count + max_step");
+}
+
+fn rtc_reset_flow<P: Profile>() {
+    let clock = SimClock::default();
+    let mut rtc = FakeClockRtc::new(clock.clone(), Datetime::default());
+    let reset = RtcReset::prepare(&mut rtc);
+    // The clock counts on through the reset and the boot after it
+    clock.advance_ns(1_500_000_000);
+
+    let mut screens = ScreensGen::new(SimDisplay::<P>::new(), ScriptedInput::new(1));
+    screens.run_rtc_reset_test(&mut rtc, reset);
+    // The time and that it counts, each backup register, then the tamper timestamp from before
+    // the reset and a new one
+    assert_eq!(screens.check_log().passed(), 2 + 4 + 2);
+    assert_eq!(screens.check_log().failed(), 0);
+}
+
+#[test]
+fn rtc_reset_flow_metro() {
+    rtc_reset_flow::<Metro>();
+}
+
+#[test]
+fn rtc_reset_flow_pygamer() {
+    rtc_reset_flow::<Pygamer>();
+}
+
+#[test]
+fn rtc_reset_flow_catches_lost_time() {
+    let clock = SimClock::default();
+    let mut rtc = FakeClockRtc::new(clock.clone(), Datetime::default());
+    let reset = RtcReset::prepare(&mut rtc);
+    // An RTC that the reset started over, losing the time and everything else
+    let mut rtc = FakeClockRtc::new(clock.clone(), Datetime::default());
+
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(7));
+    screens.run_rtc_reset_test(&mut rtc, reset);
+    // Only the clock counting and the new tamper timestamp pass
+    assert_eq!(screens.check_log().passed(), 2);
+    assert_eq!(screens.check_log().failed(), 6);
+    let failures: Vec<_> = screens.check_log().failures().map(|f| f.expr).collect();
+    assert_eq!(failures[0], "elapsed_secs in 0..=RESET_MAX_SECS");
+}
+
+#[test]
+fn retained_value_is_taken_once() {
+    static VALUE: Retained<u32> = Retained::new();
+
+    assert_eq!(VALUE.take(), None);
+    VALUE.store(7);
+    assert_eq!(VALUE.take(), Some(7));
+    assert_eq!(VALUE.take(), None);
+}
+
+fn delay_ns_flow<P: Profile>(rtc_hz: u32) {
+    let clock = SimClock::default();
+    let mut screens = ScreensGen::new(SimDisplay::<P>::new(), ScriptedInput::new(5));
+
+    screens.run_delay_ns_test(
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeRtc::new(clock.clone(), rtc_hz),
+        rtc_hz,
+        &mut clock.clone(),
+    );
+    // The sweep for each of the three delays, then the RTC edge cases
+    assert_eq!(screens.check_log().passed(), 18 + 5);
+    assert_eq!(screens.check_log().failed(), 0);
+    let (display, input) = screens.free();
+
+    // One to start, then one after the results of each delay and of the edge cases
+    assert_eq!(input.presses(), 5);
+    // The sweep adds up to 111.111 ms for each of the three delays, and the edge cases take a
+    // bit over 20 ms plus up to two ticks of the RTC
+    let now = clock.now_ns();
+    assert!((353_333_000..356_000_000).contains(&now), "{now}");
+    assert!(!display.screens().is_empty());
+}
+
+#[test]
+fn delay_ns_flow_metro() {
+    delay_ns_flow::<Metro>(1024);
+}
+
+#[test]
+fn delay_ns_flow_pygamer() {
+    delay_ns_flow::<Pygamer>(32768);
+}
+
+/// A delay that runs a fifth over, like a timer with the wrong prescaler.
+struct SlowDelay(SimClock);
+impl DelayNs for SlowDelay {
+    fn delay_ns(&mut self, ns: u32) {
+        self.0.advance_ns(u64::from(ns) * 6 / 5);
+    }
+}
+
+#[test]
+fn delay_ns_flow_catches_slow_delay() {
+    let clock = SimClock::default();
+    // One more press for the screen of each failure
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(5 + 3));
+
+    screens.run_delay_ns_test(
+        &mut FakeDelay::new(clock.clone()),
+        &mut SlowDelay(clock.clone()),
+        &mut FakeRtc::new(clock.clone(), 1024),
+        1024,
+        &mut clock.clone(),
+    );
+
+    // Only the delays long enough for a fifth to outgrow the slack fail
+    assert_eq!(screens.check_log().failed(), 3);
+    assert_eq!(screens.check_log().passed(), 15 + 5);
+}
+
+/// A stopwatch that counts with the 1 kHz RTC, which is too coarse for the shortest delays.
+struct RtcStopwatch(SimClock);
+impl Stopwatch for RtcStopwatch {
+    fn rate_hz(&self) -> u32 {
+        1024
+    }
+
+    fn ticks(&mut self) -> u32 {
+        (self.0.now_ns() * 1024 / 1_000_000_000) as u32
+    }
+
+    fn uses_rtc(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn delay_ns_flow_coarse_stopwatch() {
+    let clock = SimClock::default();
+    // Skipping the RTC saves its button press
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(4));
+
+    screens.run_delay_ns_test(
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeRtc::new(clock.clone(), 1024),
+        1024,
+        &mut RtcStopwatch(clock.clone()),
+    );
+
+    // Only 10 ms and 100 ms are long enough to time, but the RTC edge cases still run
+    assert_eq!(screens.check_log().passed(), 4 + 5);
+    assert_eq!(screens.check_log().failed(), 0);
+}
+
+#[test]
+fn clock_mode_leap_day() {
+    let clock = SimClock::default();
+    let before_midnight = |year| Datetime {
+        seconds: 59,
+        minutes: 59,
+        hours: 23,
+        day: 28,
+        month: 2,
+        year,
+    };
+    let mut rtc = FakeClockRtc::new(clock.clone(), before_midnight(24));
+
+    clock.advance_ns(1_000_000_000);
+    let time = rtc.current_time();
+    assert_eq!((time.month, time.day), (2, 29));
+
+    rtc.set_time(before_midnight(25));
+    clock.advance_ns(1_000_000_000);
+    let time = rtc.current_time();
+    assert_eq!((time.month, time.day), (3, 1));
+}
+
+#[test]
+fn clock_mode_rejects_invalid_dates() {
+    let clock = SimClock::default();
+    let date = |year, month, day| Datetime {
+        day,
+        month,
+        year,
+        ..Default::default()
+    };
+    let mut rtc = FakeClockRtc::new(clock.clone(), date(24, 2, 29));
+
+    assert_eq!(rtc.try_set_time(date(25, 2, 29)), Err(InvalidDatetime));
+    assert_eq!(rtc.try_set_time(date(64, 1, 1)), Err(InvalidDatetime));
+    assert_eq!(rtc.current_time(), date(24, 2, 29));
+
+    assert_eq!(rtc.try_set_time(date(63, 12, 31)), Ok(()));
+    assert_eq!(rtc.current_time(), date(63, 12, 31));
+}
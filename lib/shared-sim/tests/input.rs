@@ -0,0 +1,117 @@
+//! Debouncing, long presses and timeouts of the event-based input, blocking and async.
+use shared::input::{DEBOUNCE_MS, Debouncer, LONG_PRESS_MS};
+use shared_sim::prelude::*;
+
+const A: u8 = ButtonId::A.mask();
+const B: u8 = ButtonId::B.mask();
+
+/// Feeds the same sample for `ms`, one millisecond at a time, collecting the events.
+fn hold(debouncer: &mut Debouncer, sample: u8, ms: u32) -> Vec<ButtonEvent> {
+    let mut events = Vec::new();
+    for _ in 0..ms {
+        debouncer.update(sample, 1);
+        events.extend(core::iter::from_fn(|| debouncer.next_event()));
+    }
+    events
+}
+
+#[test]
+fn ignores_bounces() {
+    let mut debouncer = Debouncer::new();
+
+    // Contact bounce shorter than the debounce time is never reported
+    for _ in 0..5 {
+        assert!(hold(&mut debouncer, A, DEBOUNCE_MS - 1).is_empty());
+        assert!(hold(&mut debouncer, 0, 3).is_empty());
+    }
+    assert!(!debouncer.is_pressed(ButtonId::A));
+
+    assert_eq!(
+        hold(&mut debouncer, A, DEBOUNCE_MS + 1),
+        [ButtonEvent::Pressed(ButtonId::A)]
+    );
+    assert!(debouncer.is_pressed(ButtonId::A));
+    assert_eq!(
+        hold(&mut debouncer, 0, DEBOUNCE_MS + 1),
+        [ButtonEvent::Released(ButtonId::A)]
+    );
+}
+
+#[test]
+fn reports_long_press_once() {
+    let mut debouncer = Debouncer::new();
+
+    let events = hold(&mut debouncer, A, DEBOUNCE_MS + 2 * LONG_PRESS_MS);
+    assert_eq!(
+        events,
+        [
+            ButtonEvent::Pressed(ButtonId::A),
+            ButtonEvent::LongPress(ButtonId::A)
+        ]
+    );
+
+    // Another press can be long again
+    hold(&mut debouncer, 0, DEBOUNCE_MS + 1);
+    let events = hold(&mut debouncer, A, DEBOUNCE_MS + LONG_PRESS_MS);
+    assert_eq!(events.last(), Some(&ButtonEvent::LongPress(ButtonId::A)));
+}
+
+#[test]
+fn keeps_events_in_order() {
+    let mut debouncer = Debouncer::new();
+
+    // Nothing takes the events until both buttons have been pressed and released
+    for sample in [A, A | B, B, 0] {
+        for _ in 0..=DEBOUNCE_MS {
+            debouncer.update(sample, 1);
+        }
+    }
+
+    let events: Vec<_> = core::iter::from_fn(|| debouncer.next_event()).collect();
+    assert_eq!(
+        events,
+        [
+            ButtonEvent::Pressed(ButtonId::A),
+            ButtonEvent::Pressed(ButtonId::B),
+            ButtonEvent::Released(ButtonId::A),
+            ButtonEvent::Released(ButtonId::B),
+        ]
+    );
+}
+
+#[test]
+fn button_timeout() {
+    let mut input = ScriptedInput::new(0).events([
+        (50, ButtonEvent::Released(ButtonId::A)),
+        (100, ButtonEvent::Pressed(ButtonId::B)),
+    ]);
+
+    // A release is not a press
+    assert_eq!(input.wait_for_button_timeout(100), None);
+    assert_eq!(input.polls(), 100);
+    assert_eq!(input.wait_for_button_timeout(100), Some(ButtonId::B));
+}
+
+#[test]
+fn poll_event_does_not_block() {
+    let mut input = ScriptedInput::new(0).events([(10, ButtonEvent::Pressed(ButtonId::C))]);
+
+    assert_eq!(input.poll_event(), None);
+    assert_eq!(input.polls(), 1);
+    assert_eq!(input.wait_for_event(), ButtonEvent::Pressed(ButtonId::C));
+    assert_eq!(input.poll_event(), None);
+}
+
+#[test]
+fn async_press_skips_other_events() {
+    let mut input = ScriptedInput::new(0).events([
+        (5, ButtonEvent::Pressed(ButtonId::A)),
+        (0, ButtonEvent::Released(ButtonId::A)),
+        (10, ButtonEvent::Pressed(ButtonId::B)),
+    ]);
+
+    assert_eq!(block_on(input.press()), ButtonId::A);
+    assert_eq!(block_on(input.press()), ButtonId::B);
+    // The future stays pending through every empty poll, one per millisecond
+    assert_eq!(input.polls(), 5 + 1 + 1 + 10 + 1);
+}
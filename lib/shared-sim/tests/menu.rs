@@ -0,0 +1,104 @@
+//! Picks tests from the on-device menu of the test registry.
+use shared::{input::POLL_MS, mono_backend::SELECT_TIMEOUT_MS};
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+
+type Screens<P> = ScreensGen<SimDisplay<P>, ScriptedInput>;
+
+#[derive(Default)]
+struct Context {
+    runs: Vec<&'static str>,
+}
+
+fn tests<P: Profile>() -> [HwTest<SimDisplay<P>, ScriptedInput, Context>; 3] {
+    [
+        HwTest::new("rtc", |_, ctx| ctx.runs.push("rtc")),
+        HwTest::new("delay_ns", |_, ctx| ctx.runs.push("delay_ns")),
+        HwTest::new("dsu", |screens, ctx| {
+            check_eq!(screens, 1u32, 1);
+            ctx.runs.push("dsu");
+        }),
+    ]
+}
+
+fn navigating<P: Profile>(navs: impl IntoIterator<Item = Nav>) -> Screens<P> {
+    ScreensGen::new(SimDisplay::new(), ScriptedInput::unlimited().navigate(navs))
+}
+
+#[test]
+fn menu_wraps_around() {
+    let items = ["a", "b", "c"];
+    let mut screens = navigating::<Metro>([Nav::Previous, Nav::Select]);
+    assert_eq!(screens.menu("Pick:", &items, |i| i), 2);
+
+    let mut screens =
+        navigating::<Metro>([Nav::Next, Nav::Next, Nav::Next, Nav::Next, Nav::Select]);
+    assert_eq!(screens.menu("Pick:", &items, |i| i), 1);
+}
+
+#[test]
+fn runs_selected_tests() {
+    let tests = tests::<Metro>();
+    let mut ctx = Context::default();
+    let mut screens =
+        navigating::<Metro>([Nav::Next, Nav::Select, Nav::Next, Nav::Next, Nav::Select]);
+
+    screens.run_test_from_menu(&tests, &mut ctx);
+    screens.run_test_from_menu(&tests, &mut ctx);
+
+    assert_eq!(ctx.runs, ["delay_ns", "dsu"]);
+    // The check log belongs to the test that was run last
+    assert_eq!(screens.check_log().passed(), 1);
+    // One button press after each test to return to the menu
+    let (_, input) = screens.free();
+    assert_eq!(input.presses(), 2);
+}
+
+fn menu_screen<P: Profile>(name: &str) {
+    let tests = tests::<P>();
+    let mut screens = navigating::<P>([Nav::Next, Nav::Next, Nav::Select]);
+    screens.run_test_from_menu(&tests, &mut Context::default());
+
+    // The third screen has the last test selected
+    let (display, _) = screens.free();
+    assert_snapshot(name, &display.screens()[2]);
+}
+
+#[test]
+fn menu_screen_metro() {
+    menu_screen::<Metro>("menu_metro");
+}
+
+#[test]
+fn menu_screen_pygamer() {
+    menu_screen::<Pygamer>("menu_pygamer");
+}
+
+#[test]
+fn picks_monotonic_from_menu_without_stored_pick() {
+    let mut screens = navigating::<Metro>([Nav::Next, Nav::Select]);
+    assert_eq!(screens.pick_mono_backend(None), MonoBackend::Rtc);
+}
+
+#[test]
+fn keeps_stored_monotonic_without_press() {
+    let mut screens = navigating::<Metro>([]);
+    assert_eq!(
+        screens.pick_mono_backend(Some(MonoBackend::Rtc)),
+        MonoBackend::Rtc
+    );
+
+    let (_, input) = screens.free();
+    assert_eq!(input.polls(), SELECT_TIMEOUT_MS / POLL_MS);
+}
+
+#[test]
+fn press_changes_stored_monotonic() {
+    let input = ScriptedInput::new(0)
+        .events([(500, ButtonEvent::Pressed(ButtonId::B))])
+        .navigate([Nav::Select]);
+    let mut screens = ScreensGen::<SimDisplay<Pygamer>, _>::new(SimDisplay::new(), input);
+    assert_eq!(
+        screens.pick_mono_backend(Some(MonoBackend::Rtc)),
+        MonoBackend::SysTick
+    );
+}
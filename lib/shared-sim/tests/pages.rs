@@ -0,0 +1,91 @@
+//! Output shown a page at a time, flipped with the menu buttons.
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+
+fn numbered_lines<const N: usize>(count: usize) -> PagedWriter<N> {
+    let mut text = PagedWriter::new();
+    for i in 0..count {
+        writeln!(text, "line {i}").unwrap();
+    }
+    text
+}
+
+#[test]
+fn steps_through_pages() {
+    // Nine lines fit above the footer of the Metro display
+    let text = numbered_lines::<1024>(30);
+    let mut screens = ScreensGen::new(
+        SimDisplay::<Metro>::new(),
+        ScriptedInput::new(0).navigate([
+            Nav::Next,
+            Nav::Next,
+            Nav::Previous,
+            Nav::Next,
+            Nav::Next,
+            Nav::Next,
+        ]),
+    );
+
+    let _ = screens.show_pages(&text);
+
+    // Pages 1, 2, 3, 2, 3 and 4, with next on the last page continuing
+    let (display, _) = screens.free();
+    let screens = display.screens();
+    assert_eq!(screens.len(), 6);
+    assert_eq!(screens[1], screens[3]);
+    assert_eq!(screens[2], screens[4]);
+}
+
+#[test]
+fn select_continues_from_any_page() {
+    let text = numbered_lines::<1024>(30);
+    let mut screens = ScreensGen::new(
+        SimDisplay::<Metro>::new(),
+        ScriptedInput::new(0).navigate([Nav::Previous, Nav::Select]),
+    );
+
+    let _ = screens.show_pages(&text);
+
+    // Previous on the first page stays there
+    let (display, _) = screens.free();
+    assert_eq!(display.screens().len(), 1);
+}
+
+#[test]
+fn truncates_at_capacity() {
+    let mut text = PagedWriter::<8>::new();
+    write!(text, "abcdef").unwrap();
+    assert!(!text.truncated());
+
+    // The two byte character does not fit
+    write!(text, "gé").unwrap();
+    assert_eq!(text.as_str(), "abcdefg");
+    assert!(text.truncated());
+}
+
+fn second_page<P: Profile>(name: &str) {
+    let mut text = numbered_lines::<1024>(20);
+    write!(
+        text,
+        "and a last line that is far too long to fit across the width of either display"
+    )
+    .unwrap();
+    let mut screens = ScreensGen::new(
+        SimDisplay::<P>::new(),
+        ScriptedInput::new(0).navigate([Nav::Next, Nav::Select]),
+    );
+
+    let _ = screens.show_pages(&text);
+
+    let (display, _) = screens.free();
+    assert_snapshot(name, &display.screens()[1]);
+}
+
+#[test]
+fn second_page_metro() {
+    second_page::<Metro>("pages_metro");
+}
+
+#[test]
+fn second_page_pygamer() {
+    second_page::<Pygamer>("pages_pygamer");
+}
@@ -0,0 +1,94 @@
+//! Checks the panic record that is kept across a reset and shown on the next boot.
+//!
+//! The record and the result sink are global, so only one test stores a panic.
+use core::panic::Location;
+use shared::{
+    input::POLL_MS,
+    panic_record::{LOCATION_LEN, MESSAGE_LEN, SHOW_TIMEOUT_MS},
+};
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Clone, Default)]
+struct Capture(Rc<RefCell<String>>);
+impl Write for Capture {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.borrow_mut().push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn record_keeps_message_and_location() {
+    let location = Location::caller();
+    let record = PanicRecord::new(format_args!("index {} out of range", 3), Some(location), 1);
+
+    assert_eq!(record.message(), "index 3 out of range");
+    assert_eq!(record.location(), Some(location.to_string().as_str()));
+    assert_eq!(
+        record.to_string(),
+        format!("panicked at {location}:\nindex 3 out of range")
+    );
+
+    let record = PanicRecord::new("explicit panic", None, 1);
+    assert_eq!(record.location(), None);
+    assert_eq!(record.to_string(), "panicked:\nexplicit panic");
+}
+
+#[test]
+fn long_messages_are_cut_short() {
+    let message = "ü".repeat(MESSAGE_LEN);
+    let record = PanicRecord::new(&message, Some(Location::caller()), 1);
+
+    // Only whole characters are kept
+    assert_eq!(record.message(), "ü".repeat(MESSAGE_LEN / 2));
+    assert!(record.to_string().ends_with("ü..."));
+    assert!(record.location().unwrap().len() <= LOCATION_LEN);
+}
+
+#[test]
+fn panics_are_counted_until_power_off() {
+    assert!(PanicRecord::take_previous().is_none());
+
+    PanicRecord::store("first", None);
+    PanicRecord::store("second", Some(Location::caller()));
+
+    // After the reset
+    let record = PanicRecord::take_previous().unwrap();
+    assert_eq!(record.message(), "second");
+    assert_eq!(record.faults(), 2);
+    assert!(PanicRecord::take_previous().is_none());
+    assert_eq!(PanicRecord::previous().unwrap().message(), "second");
+
+    PanicRecord::store("third", None);
+    let record = PanicRecord::take_previous().unwrap();
+    assert_eq!(record.faults(), 3);
+
+    // The panic follows the hello record once results are emitted
+    let capture = Capture::default();
+    set_result_sink(Box::leak(Box::new(capture.clone())));
+    assert_eq!(
+        *capture.0.borrow(),
+        "@atr hello 1\r\n@atr fault 3 panicked:\\nthird\r\n"
+    );
+}
+
+fn panic_screen<P: Profile>(name: &str) {
+    let record = PanicRecord::new("called `Option::unwrap()` on a `None` value", None, 2);
+    let mut screens = ScreensGen::new(SimDisplay::<P>::new(), ScriptedInput::new(0));
+    screens.show_panic(&record);
+
+    let (display, input) = screens.free();
+    assert_eq!(input.polls(), SHOW_TIMEOUT_MS / POLL_MS);
+    assert_snapshot(name, &display.screens()[0]);
+}
+
+#[test]
+fn panic_screen_metro() {
+    panic_screen::<Metro>("panic_metro");
+}
+
+#[test]
+fn panic_screen_pygamer() {
+    panic_screen::<Pygamer>("panic_pygamer");
+}
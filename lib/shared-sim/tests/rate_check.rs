@@ -0,0 +1,58 @@
+//! Checks the comparison of a clock against one second of the RTC, as done for SysTick at boot.
+use shared::{
+    rate_check::{MAX_RATE_ERROR_PPM, RateCheck, check_rate},
+    rtc::RtcCount32,
+    tests::Stopwatch,
+};
+use shared_sim::{FakeRtc, SimClock};
+
+/// A core clock at `actual_hz` that is thought to run at `claimed_hz`.
+struct CoreClock {
+    clock: SimClock,
+    claimed_hz: u32,
+    actual_hz: u64,
+}
+impl Stopwatch for CoreClock {
+    fn rate_hz(&self) -> u32 {
+        self.claimed_hz
+    }
+
+    fn ticks(&mut self) -> u32 {
+        (self.clock.now_ns() * self.actual_hz / 1_000_000_000) as u32
+    }
+}
+
+fn check_core_clock(claimed_hz: u32, actual_hz: u64) -> RateCheck {
+    let clock = SimClock::default();
+    let rtc = FakeRtc::new(clock.clone(), 1024);
+    let mut core = CoreClock {
+        clock,
+        claimed_hz,
+        actual_hz,
+    };
+    check_rate(&mut core, || rtc.count32(), 1024)
+}
+
+#[test]
+fn clock_at_its_rate_passes() {
+    let clock = SimClock::default();
+    let rtc = FakeRtc::new(clock.clone(), 1024);
+    let check = check_rate(&mut clock.clone(), || rtc.count32(), 1024);
+
+    assert_eq!(check.expected_hz, 1_000_000_000);
+    assert!(check.error_ppm().abs() < 100, "{check:?}");
+    assert!(check.passed());
+}
+
+#[test]
+fn core_clock_at_the_wrong_rate_fails() {
+    // SysTick started for 120 MHz on the 48 MHz Metro M0
+    let check = check_core_clock(120_000_000, 48_000_000);
+
+    assert!(check.measured_hz.abs_diff(48_000_000) < 48_000_000 / 10_000);
+    assert!(check.error_ppm() < -MAX_RATE_ERROR_PPM);
+    assert!(!check.passed());
+
+    assert!(check_core_clock(48_000_000, 48_000_000).passed());
+    assert!(!check_core_clock(48_000_000, 120_000_000).passed());
+}
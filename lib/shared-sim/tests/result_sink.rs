@@ -0,0 +1,33 @@
+//! Checks that records emitted while the result sink is being written to still get out.
+//!
+//! The result sink is global, so this is kept apart from the other tests.
+use shared_sim::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+/// An output that emits a record in the middle of the first write, as an interrupt could.
+#[derive(Clone, Default)]
+struct Interrupted(Rc<RefCell<String>>);
+impl Write for Interrupted {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let first = self.0.borrow().is_empty();
+        self.0.borrow_mut().push_str(s);
+        if first {
+            emit_results(|r| r.test("interrupt"));
+            // Only written out once this write is done
+            assert_eq!(*self.0.borrow(), s);
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn records_emitted_while_writing_follow() {
+    let output = Interrupted::default();
+    set_result_sink(Box::leak(Box::new(output.clone())));
+    emit_results(|r| r.end());
+
+    assert_eq!(
+        *output.0.borrow(),
+        "@atr hello 1\r\n@atr test interrupt\r\n@atr end\r\n"
+    );
+}
@@ -0,0 +1,57 @@
+//! Checks the result records emitted by the `shared` test flows.
+//!
+//! The result sink is global, so this is kept apart from the other flow tests.
+use shared::results::{BUFFER_LEN, RecordBuffer, ResultWriter};
+use shared_sim::prelude::*;
+use std::{cell::RefCell, rc::Rc};
+
+#[derive(Clone, Default)]
+struct Capture(Rc<RefCell<String>>);
+impl Write for Capture {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0.borrow_mut().push_str(s);
+        Ok(())
+    }
+}
+
+#[test]
+fn delay_ns_flow_results() {
+    let capture = Capture::default();
+    set_result_sink(Box::leak(Box::new(capture.clone())));
+
+    let clock = SimClock::default();
+    let mut screens = ScreensGen::new(SimDisplay::<Metro>::new(), ScriptedInput::new(5));
+    screens.start_test("delay_ns");
+    screens.run_delay_ns_test(
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeDelay::new(clock.clone()),
+        &mut FakeRtc::new(clock.clone(), 1024),
+        1024,
+        &mut clock.clone(),
+    );
+
+    let output = capture.0.borrow();
+    let mut lines = output.lines();
+    assert_eq!(lines.next(), Some("@atr hello 1"));
+    assert_eq!(lines.next(), Some("@atr test delay_ns"));
+    // Each step is checked under its own name, then its measurement is recorded
+    assert_eq!(lines.next(), Some("@atr case Delay/1us pass"));
+    assert_eq!(lines.next(), Some("@atr value measured_ns 1000"));
+    // The rest of the 18 steps of the sweep for the three delays, then the five checks of the RTC
+    // edge cases
+    assert_eq!(lines.filter(|l| l.starts_with("@atr case")).count(), 17 + 5);
+    assert!(output.contains("@atr case Rtc/100ms pass\r\n@atr value measured_ns 100000000\r\n"));
+}
+
+#[test]
+fn records_that_do_not_fit_are_dropped_whole() {
+    let mut buffer = RecordBuffer::new();
+    let mut writer = ResultWriter::new(&mut buffer);
+    writer.test("rtc").unwrap();
+    let long = "x".repeat(BUFFER_LEN);
+    assert!(writer.case("count32", Outcome::Fail, Some(&long)).is_err());
+    // Nothing more gets in until the buffer has been written out
+    assert!(writer.end().is_err());
+
+    assert_eq!(buffer.as_str(), "@atr test rtc\r\n");
+}
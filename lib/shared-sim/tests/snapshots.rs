@@ -0,0 +1,68 @@
+//! Golden-image tests of the text layout done by `DisplayWriter` on both display geometries.
+use embedded_graphics::prelude::*;
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+
+/// Writes to a blank display of each geometry and compares the results with the golden images.
+fn check(name: &str, write: impl Fn(&mut dyn core::fmt::Write)) {
+    let mut display = SimDisplay::<Metro>::new();
+    let style = display.display_text_style(Point::zero());
+    write(&mut DisplayWriter::new(&mut display, style));
+    assert_snapshot(&format!("{name}_metro"), display.frame());
+
+    let mut display = SimDisplay::<Pygamer>::new();
+    let style = display.display_text_style(Point::zero());
+    write(&mut DisplayWriter::new(&mut display, style));
+    assert_snapshot(&format!("{name}_pygamer"), display.frame());
+}
+
+#[test]
+fn single_line() {
+    check("single_line", |w| write!(w, "Hello world!").unwrap());
+}
+
+#[test]
+fn wrapping() {
+    check("wrapping", |w| {
+        write!(
+            w,
+            "This line is much too long to fit across the width of either display, so it wraps."
+        )
+        .unwrap()
+    });
+}
+
+#[test]
+fn exact_width() {
+    // Both displays are exactly 32 characters wide
+    check("exact_width", |w| {
+        writeln!(w, "0123456789ABCDEF0123456789ABCDEF").unwrap();
+        write!(w, "next").unwrap();
+    });
+}
+
+#[test]
+fn newlines() {
+    check("newlines", |w| {
+        write!(w, "line one\nline two\n\nline four").unwrap()
+    });
+}
+
+#[test]
+fn continued_writes() {
+    check("continued_writes", |w| {
+        write!(w, "first ").unwrap();
+        w.write_str("second\n").unwrap();
+        write!(w, "third").unwrap();
+        writeln!(w, " fourth").unwrap();
+        write!(w, "{:08X}", 0xE8C27689u32).unwrap();
+    });
+}
+
+#[test]
+fn overflow() {
+    check("overflow", |w| {
+        for i in 0..20 {
+            writeln!(w, "Line number {i}").unwrap();
+        }
+    });
+}
@@ -0,0 +1,47 @@
+//! Checks the summary statistics that timing tests report.
+use shared::stats::Stats;
+
+#[test]
+fn empty_stats_have_no_summary() {
+    let stats = Stats::new();
+
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.max(), None);
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.std_dev(), None);
+}
+
+#[test]
+fn stats_summarize_samples() {
+    let mut stats = Stats::new();
+    for sample in [2, 4, 4, 4, 5, 5, 7, 9] {
+        stats.add(sample);
+    }
+
+    assert_eq!(stats.count(), 8);
+    assert_eq!(stats.min(), Some(2));
+    assert_eq!(stats.max(), Some(9));
+    assert_eq!(stats.mean(), Some(5));
+    assert_eq!(stats.std_dev(), Some(2));
+}
+
+#[test]
+fn stats_handle_negative_and_large_samples() {
+    let mut stats = Stats::new();
+    for sample in [-1_000_000, 1_000_000, -1_000_000, 1_000_000] {
+        stats.add(sample);
+    }
+
+    assert_eq!(stats.min(), Some(-1_000_000));
+    assert_eq!(stats.mean(), Some(0));
+    assert_eq!(stats.std_dev(), Some(1_000_000));
+
+    // Rounds down rather than to the nearest
+    let mut stats = Stats::new();
+    for sample in [0, 1, 1] {
+        stats.add(sample);
+    }
+    assert_eq!(stats.mean(), Some(0));
+    assert_eq!(stats.std_dev(), Some(0));
+}
@@ -0,0 +1,91 @@
+//! The scrolling terminal mode of `DisplayWriter`.
+use embedded_graphics::prelude::*;
+use shared_sim::{prelude::*, snapshot::assert_snapshot};
+
+fn rows(terminal: &Terminal, count: usize) -> Vec<&str> {
+    (0..count).map(|r| terminal.row(r).trim_end()).collect()
+}
+
+fn scrolls<P: Profile>(name: &str) {
+    let mut display = SimDisplay::<P>::new();
+    let mut terminal = Terminal::new();
+    let rows_shown = (P::SIZE.height / P::FONT.character_size.height) as usize;
+
+    // Each writer continues where the last one left off
+    for i in 0..40 {
+        let style = display.display_text_style(Point::zero());
+        writeln!(
+            DisplayWriter::terminal(&mut display, style, &mut terminal),
+            "line {i}"
+        )
+        .unwrap();
+    }
+
+    // The last line is left empty for the cursor
+    let first = 41 - rows_shown;
+    let expected: Vec<_> = (first..40)
+        .map(|i| format!("line {i}"))
+        .chain([String::new()])
+        .collect();
+    assert_eq!(rows(&terminal, rows_shown), expected);
+    assert_snapshot(name, display.frame());
+}
+
+#[test]
+fn scrolls_metro() {
+    scrolls::<Metro>("terminal_scroll_metro");
+}
+
+#[test]
+fn scrolls_pygamer() {
+    scrolls::<Pygamer>("terminal_scroll_pygamer");
+}
+
+#[test]
+fn wraps_without_blank_lines() {
+    let mut display = SimDisplay::<Metro>::new();
+    let mut terminal = Terminal::new();
+    let style = display.display_text_style(Point::zero());
+    {
+        let mut writer = DisplayWriter::terminal(&mut display, style, &mut terminal);
+        writeln!(writer, "0123456789ABCDEF0123456789ABCDEF").unwrap();
+        write!(writer, "next and a line that is too long to fit on one row").unwrap();
+    }
+
+    assert_eq!(
+        rows(&terminal, 4),
+        [
+            "0123456789ABCDEF0123456789ABCDEF",
+            "next and a line that is too long",
+            " to fit on one row",
+            ""
+        ]
+    );
+}
+
+#[test]
+fn redraws_only_changed_cells() {
+    let mut display = SimDisplay::<Metro>::new();
+    let mut terminal = Terminal::new();
+    let cell = Metro::FONT.character_size;
+    let cell_pixels = (cell.width * cell.height) as usize;
+
+    let style = display.display_text_style(Point::zero());
+    write!(
+        DisplayWriter::terminal(&mut display, style, &mut terminal),
+        "count: 100"
+    )
+    .unwrap();
+    let drawn = display.pixels_drawn();
+    // The space is already blank, so it is not drawn
+    assert_eq!(drawn, 9 * cell_pixels);
+
+    // Rewriting the line in place only draws the digit that changed
+    let style = display.display_text_style(Point::zero());
+    write!(
+        DisplayWriter::terminal(&mut display, style, &mut terminal),
+        "\rcount: 101"
+    )
+    .unwrap();
+    assert_eq!(display.pixels_drawn(), drawn + cell_pixels);
+}
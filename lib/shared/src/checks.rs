@@ -0,0 +1,338 @@
+//! Checks that report failures on the screen and carry on, rather than panicking.
+//!
+//! Use the [`check!`](crate::check), [`check_eq!`](crate::check_eq) and
+//! [`check_range!`](crate::check_range) macros, which record every check into the [`CheckLog`]
+//! of the current test and emit it as a result case. A failed check shows the expected and
+//! actual values until a button is pressed, and the tally is shown by
+//! [`ScreensGen::test_complete`].
+//!
+//! The case is named after the checked expression, unless the macro is given a name first, as
+//! checks in a loop have to be:
+//! ```ignore
+//! for rollover in ROLLOVERS {
+//!     check_eq!(self, name = rollover.name, next, rollover.to);
+//! }
+//! ```
+use crate::{
+    Input,
+    display::Display,
+    results::{Outcome, emit_results},
+    screens::ScreensGen,
+};
+use core::fmt::{self, Write};
+use core::ops::{Bound, RangeBounds};
+
+/// Most failed checks whose location is kept for the tally.
+pub const MAX_FAILURES: usize = 4;
+
+/// Where a check is in the source code.
+#[derive(Clone, Copy, Debug)]
+pub struct Location {
+    /// The checked expression.
+    pub expr: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+}
+impl Location {
+    /// The name of the file without its directories, which depend on where it was built from.
+    pub fn file_name(&self) -> &'static str {
+        self.file.rsplit(['/', '\\']).next().unwrap_or(self.file)
+    }
+}
+
+/// A value that can be shown by a check.
+pub trait CheckValue {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+macro_rules! int_check_value {
+    ($($t:ty),+) => {
+        $(
+            impl CheckValue for $t {
+                fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{self:#X} ({self})")
+                }
+            }
+        )+
+    };
+}
+int_check_value!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl CheckValue for bool {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
+
+impl CheckValue for [u8] {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('[')?;
+        for (i, byte) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_char(' ')?;
+            }
+            write!(f, "{byte:02X}")?;
+        }
+        f.write_char(']')
+    }
+}
+
+impl<const N: usize> CheckValue for [u8; N] {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_value(f)
+    }
+}
+
+impl<T: CheckValue> CheckValue for Option<T> {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(value) => value.fmt_value(f),
+            None => f.write_str("none"),
+        }
+    }
+}
+
+impl<T: CheckValue + ?Sized> CheckValue for &T {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt_value(f)
+    }
+}
+
+/// Displays a [`CheckValue`].
+pub struct Shown<'a, T: ?Sized>(pub &'a T);
+impl<T: CheckValue + ?Sized> fmt::Display for Shown<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_value(f)
+    }
+}
+
+/// Displays the expected range of a [`check_range!`](crate::check_range).
+struct ShownRange<'a, T>(Bound<&'a T>, Bound<&'a T>);
+impl<T: CheckValue> fmt::Display for ShownRange<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Bound::Included(start) => write!(f, "{} <= ", Shown(start))?,
+            Bound::Excluded(start) => write!(f, "{} < ", Shown(start))?,
+            Bound::Unbounded => {}
+        }
+        f.write_str("x")?;
+        match self.1 {
+            Bound::Included(end) => write!(f, " <= {}", Shown(end)),
+            Bound::Excluded(end) => write!(f, " < {}", Shown(end)),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+/// The results of all checks of the current test.
+#[derive(Clone, Default)]
+pub struct CheckLog {
+    passed: u32,
+    failed: u32,
+    failures: [Option<Location>; MAX_FAILURES],
+}
+impl CheckLog {
+    pub fn passed(&self) -> u32 {
+        self.passed
+    }
+
+    pub fn failed(&self) -> u32 {
+        self.failed
+    }
+
+    /// The first failed checks, up to [`MAX_FAILURES`] of them.
+    pub fn failures(&self) -> impl Iterator<Item = &Location> {
+        self.failures.iter().flatten()
+    }
+
+    fn record(&mut self, passed: bool, location: Location) {
+        if passed {
+            self.passed += 1;
+        } else {
+            if let Some(slot) = self.failures.iter_mut().find(|l| l.is_none()) {
+                *slot = Some(location);
+            }
+            self.failed += 1;
+        }
+    }
+}
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Records a check as the case `name`, or as its expression if it has no name, showing the
+    /// failure screen if it failed.
+    fn record_check(
+        &mut self,
+        passed: bool,
+        location: Location,
+        name: Option<&str>,
+        expected: &dyn fmt::Display,
+        actual: &dyn fmt::Display,
+    ) -> bool {
+        self.check_log_mut().record(passed, location);
+        let name = name.unwrap_or(location.expr);
+
+        if passed {
+            emit_results(|r| r.case(name, Outcome::Pass, None));
+        } else {
+            emit_results(|r| {
+                r.case(
+                    name,
+                    Outcome::Fail,
+                    Some(&format_args!("expected {expected}, got {actual}")),
+                )
+            });
+
+            let mut writer = self.new_screen();
+            writeln!(writer, "Check failed:\n{name}").unwrap();
+            writeln!(writer, "at {}:{}\n", location.file_name(), location.line).unwrap();
+            writeln!(writer, "Expected: {expected}").unwrap();
+            writeln!(writer, "Actual: {actual}").unwrap();
+            let _ = self.wait_for_button();
+        }
+
+        passed
+    }
+
+    /// Use [`check!`](crate::check) instead.
+    pub fn check(&mut self, condition: bool, location: Location, name: Option<&str>) -> bool {
+        self.record_check(condition, location, name, &true, &condition)
+    }
+
+    /// Use [`check_eq!`](crate::check_eq) instead.
+    pub fn check_eq<T: CheckValue + PartialEq + ?Sized>(
+        &mut self,
+        actual: &T,
+        expected: &T,
+        location: Location,
+        name: Option<&str>,
+    ) -> bool {
+        self.record_check(
+            actual == expected,
+            location,
+            name,
+            &Shown(expected),
+            &Shown(actual),
+        )
+    }
+
+    /// Use [`check_range!`](crate::check_range) instead.
+    pub fn check_range<T: CheckValue + PartialOrd>(
+        &mut self,
+        value: &T,
+        range: impl RangeBounds<T>,
+        location: Location,
+        name: Option<&str>,
+    ) -> bool {
+        self.record_check(
+            range.contains(value),
+            location,
+            name,
+            &ShownRange(range.start_bound(), range.end_bound()),
+            &Shown(value),
+        )
+    }
+}
+
+/// Checks that a condition holds, reporting a failure on the screen and continuing otherwise.
+///
+/// Returns whether the check passed. The result case is named after the condition, or after
+/// `name` if one is given before it.
+#[macro_export]
+macro_rules! check {
+    (@check $screens:expr, $name:expr, $expr:expr, $cond:expr) => {
+        $screens.check(
+            $cond,
+            $crate::checks::Location {
+                expr: $expr,
+                file: file!(),
+                line: line!(),
+            },
+            $name,
+        )
+    };
+    ($screens:expr, name = $name:expr, $cond:expr $(,)?) => {
+        $crate::check!(@check $screens, Some($name), stringify!($cond), $cond)
+    };
+    ($screens:expr, $cond:expr $(,)?) => {
+        $crate::check!(@check $screens, None, stringify!($cond), $cond)
+    };
+}
+
+/// Checks that a value equals the expected one, reporting both in hex and decimal on the screen
+/// and continuing otherwise.
+///
+/// Returns whether the check passed. The result case can be named as with [`check!`].
+#[macro_export]
+macro_rules! check_eq {
+    (@check $screens:expr, $name:expr, $expr:expr, $actual:expr, $expected:expr) => {
+        $screens.check_eq(
+            &$actual,
+            &$expected,
+            $crate::checks::Location {
+                expr: $expr,
+                file: file!(),
+                line: line!(),
+            },
+            $name,
+        )
+    };
+    ($screens:expr, name = $name:expr, $actual:expr, $expected:expr $(,)?) => {
+        $crate::check_eq!(
+            @check $screens,
+            Some($name),
+            concat!(stringify!($actual), " == ", stringify!($expected)),
+            $actual,
+            $expected
+        )
+    };
+    ($screens:expr, $actual:expr, $expected:expr $(,)?) => {
+        $crate::check_eq!(
+            @check $screens,
+            None,
+            concat!(stringify!($actual), " == ", stringify!($expected)),
+            $actual,
+            $expected
+        )
+    };
+}
+
+/// Checks that a value is within a range, reporting it and the range on the screen and
+/// continuing otherwise.
+///
+/// Returns whether the check passed. The result case can be named as with [`check!`].
+#[macro_export]
+macro_rules! check_range {
+    (@check $screens:expr, $name:expr, $expr:expr, $value:expr, $range:expr) => {
+        $screens.check_range(
+            &$value,
+            $range,
+            $crate::checks::Location {
+                expr: $expr,
+                file: file!(),
+                line: line!(),
+            },
+            $name,
+        )
+    };
+    ($screens:expr, name = $name:expr, $value:expr, $range:expr $(,)?) => {
+        $crate::check_range!(
+            @check $screens,
+            Some($name),
+            concat!(stringify!($value), " in ", stringify!($range)),
+            $value,
+            $range
+        )
+    };
+    ($screens:expr, $value:expr, $range:expr $(,)?) => {
+        $crate::check_range!(
+            @check $screens,
+            None,
+            concat!(stringify!($value), " in ", stringify!($range)),
+            $value,
+            $range
+        )
+    };
+}
@@ -0,0 +1,139 @@
+//! Tracking of periodic wakeups against their deadlines, which catches timers that fire late as
+//! well as ones that never fire.
+//!
+//! A wakeup is missed when it comes a whole period or more after it was expected, by which time
+//! the next one would have been due. Tasks that are still asleep past that are found by the
+//! deadline of their sleep, which is kept in a ring of slots of [`SLOT_US`] each.
+
+/// How many slots the ring has.
+const SLOTS: usize = 32;
+/// How much time each slot of the ring covers.
+pub const SLOT_US: u64 = 500_000;
+/// The longest period whose sleeps are tracked until they wake, as the deadline of a sleep is
+/// two periods away, and the ring has to hold it apart from the slots of sleeps that are due.
+pub const MAX_TRACKED_PERIOD_US: u64 = (SLOTS as u64 - 2) * SLOT_US / 2;
+
+/// The number of sleeps with deadlines in a slot that are yet to wake.
+#[derive(Clone, Copy, Debug)]
+struct Slot {
+    /// Which slot of time since the start this is, as the ring reuses each of them.
+    number: u64,
+    pending: u32,
+}
+
+/// A sleep of a task, to be handed back to [`Deadlines::wake`].
+#[derive(Clone, Copy, Debug)]
+#[must_use]
+pub struct Sleep {
+    expected_us: u64,
+    period_us: u64,
+    tracked: bool,
+}
+impl Sleep {
+    /// When the task should wake.
+    pub fn expected_us(&self) -> u64 {
+        self.expected_us
+    }
+
+    fn deadline_us(&self) -> u64 {
+        self.expected_us + self.period_us
+    }
+}
+
+/// The wakeups of a group of periodic tasks, with times in microseconds since any start.
+#[derive(Clone, Copy, Debug)]
+pub struct Deadlines {
+    wakeups: u32,
+    misses: u32,
+    lost: u32,
+    worst_lateness_us: u64,
+    slots: [Slot; SLOTS],
+}
+impl Deadlines {
+    pub const fn new() -> Self {
+        Self {
+            wakeups: 0,
+            misses: 0,
+            lost: 0,
+            worst_lateness_us: 0,
+            slots: [Slot {
+                number: 0,
+                pending: 0,
+            }; SLOTS],
+        }
+    }
+
+    /// Records a task going to sleep at `now_us` to wake `period_us` later.
+    pub fn sleep(&mut self, now_us: u64, period_us: u64) -> Sleep {
+        let sleep = Sleep {
+            expected_us: now_us + period_us,
+            period_us,
+            tracked: period_us <= MAX_TRACKED_PERIOD_US,
+        };
+        if sleep.tracked {
+            let number = sleep.deadline_us() / SLOT_US;
+            let slot = &mut self.slots[number as usize % SLOTS];
+            if slot.number != number {
+                // Anything still there has been asleep for a whole turn of the ring
+                self.lost += slot.pending;
+                *slot = Slot { number, pending: 0 };
+            }
+            slot.pending += 1;
+        }
+        sleep
+    }
+
+    /// Records the task of `sleep` waking at `now_us`, returning how late it was.
+    pub fn wake(&mut self, sleep: Sleep, now_us: u64) -> u64 {
+        let lateness_us = now_us.saturating_sub(sleep.expected_us);
+        self.wakeups += 1;
+        self.worst_lateness_us = self.worst_lateness_us.max(lateness_us);
+
+        if sleep.tracked {
+            let number = sleep.deadline_us() / SLOT_US;
+            let slot = &mut self.slots[number as usize % SLOTS];
+            if slot.number != number {
+                // Already counted as lost
+                return lateness_us;
+            }
+            slot.pending -= 1;
+        }
+        if lateness_us >= sleep.period_us {
+            self.misses += 1;
+        }
+        lateness_us
+    }
+
+    /// How many tasks have woken.
+    pub fn wakeups(&self) -> u32 {
+        self.wakeups
+    }
+
+    /// How many tasks woke a period or more late.
+    pub fn misses(&self) -> u32 {
+        self.misses
+    }
+
+    /// How many tasks are still asleep at `now_us` although the deadlines of all of their
+    /// slots have passed, or have been asleep so long that their slots were reused.
+    pub fn overdue(&self, now_us: u64) -> u32 {
+        let current = now_us / SLOT_US;
+        let asleep: u32 = self
+            .slots
+            .iter()
+            .filter(|slot| slot.number < current)
+            .map(|slot| slot.pending)
+            .sum();
+        asleep + self.lost
+    }
+
+    /// The most that any wakeup was late by.
+    pub fn worst_lateness_us(&self) -> u64 {
+        self.worst_lateness_us
+    }
+}
+impl Default for Deadlines {
+    fn default() -> Self {
+        Self::new()
+    }
+}
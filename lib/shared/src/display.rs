@@ -67,6 +67,139 @@ pub trait Display: DrawTarget + OriginDimensions {
     }
 }
 
+/// Most character columns of a [`Terminal`], which fits both displays with their fonts.
+pub const TERMINAL_COLUMNS: usize = 32;
+/// Most character rows of a [`Terminal`].
+pub const TERMINAL_ROWS: usize = 16;
+
+type Cells = [[u8; TERMINAL_COLUMNS]; TERMINAL_ROWS];
+
+/// The character cells of a scrolling terminal, which keep their contents between writers.
+///
+/// Text scrolls up once the bottom of the box is reached rather than being dropped, and only the
+/// cells that changed since the last write are redrawn. This assumes that the box was blank when
+/// the terminal was created or last cleared.
+pub struct Terminal {
+    /// The characters that should be shown.
+    cells: Cells,
+    /// The characters that are currently drawn on the display.
+    drawn: Cells,
+    /// Cursor location in character space.
+    cursor: Point,
+}
+impl Terminal {
+    pub const fn new() -> Self {
+        Self {
+            cells: [[b' '; TERMINAL_COLUMNS]; TERMINAL_ROWS],
+            drawn: [[b' '; TERMINAL_COLUMNS]; TERMINAL_ROWS],
+            cursor: Point::zero(),
+        }
+    }
+
+    /// Forgets the contents, for when the display has been cleared.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The characters currently in a row, including trailing spaces.
+    pub fn row(&self, row: usize) -> &str {
+        // Only ASCII is ever stored
+        core::str::from_utf8(&self.cells[row]).unwrap()
+    }
+
+    /// The size of the terminal in characters, limited to what the cells can hold.
+    fn size<C>(style: &DisplayTextStyle<C>) -> Size {
+        let max = Size::new(TERMINAL_COLUMNS as u32, TERMINAL_ROWS as u32);
+        style.char_box_size.map_or(max, |s| s.component_min(max))
+    }
+
+    fn newline(&mut self, rows: usize) {
+        self.cursor.x = 0;
+        if (self.cursor.y as usize) + 1 < rows {
+            self.cursor.y += 1;
+        } else {
+            self.cells[..rows].rotate_left(1);
+            self.cells[rows - 1] = [b' '; TERMINAL_COLUMNS];
+        }
+    }
+
+    fn write(&mut self, s: &str, size: Size) {
+        let (columns, rows) = (size.width as usize, size.height as usize);
+
+        for c in s.chars() {
+            match c {
+                '\n' => self.newline(rows),
+                '\r' => self.cursor.x = 0,
+                c => {
+                    // Wrap only once there is something to put on the next line
+                    if self.cursor.x as usize >= columns {
+                        self.newline(rows);
+                    }
+                    let c = if c.is_ascii() && !c.is_ascii_control() {
+                        c as u8
+                    } else {
+                        b'?'
+                    };
+                    self.cells[self.cursor.y as usize][self.cursor.x as usize] = c;
+                    self.cursor.x += 1;
+                }
+            }
+        }
+    }
+
+    /// Draws each run of cells that differs from what is on the display.
+    fn redraw<D: Display>(
+        &mut self,
+        display: &mut D,
+        style: &DisplayTextStyle<D::Color>,
+        size: Size,
+    ) -> core::fmt::Result {
+        let char_size = style.character_style.font.character_size;
+
+        for (y, (row, drawn)) in self
+            .cells
+            .iter()
+            .zip(self.drawn.iter_mut())
+            .take(size.height as usize)
+            .enumerate()
+        {
+            let columns = size.width as usize;
+            let mut x = 0;
+            while x < columns {
+                if row[x] == drawn[x] {
+                    x += 1;
+                    continue;
+                }
+                let end = (x..columns)
+                    .find(|&i| row[i] == drawn[i])
+                    .unwrap_or(columns);
+
+                text::Text::with_text_style(
+                    core::str::from_utf8(&row[x..end]).unwrap(),
+                    style.position
+                        + Point::new(
+                            x as i32 * char_size.width as i32,
+                            y as i32 * char_size.height as i32,
+                        ),
+                    style.character_style,
+                    style.text_style,
+                )
+                .draw(display)
+                .map_err(|_| core::fmt::Error)?;
+                drawn[x..end].copy_from_slice(&row[x..end]);
+                x = end;
+            }
+        }
+
+        Ok(())
+    }
+}
+impl Default for Terminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 pub struct DisplayWriter<'a, D: Display> {
     /// The display to which to write
     display: &'a mut D,
@@ -74,6 +207,8 @@ pub struct DisplayWriter<'a, D: Display> {
     display_text_style: DisplayTextStyle<<D as DrawTarget>::Color>,
     /// Current cursor location in character space.
     char_cursor: Point,
+    /// The cells of the terminal, when scrolling rather than dropping text beyond the box.
+    terminal: Option<&'a mut Terminal>,
 }
 impl<'a, D: Display> DisplayWriter<'a, D> {
     pub fn new(display: &'a mut D, text_style: DisplayTextStyle<<D as DrawTarget>::Color>) -> Self {
@@ -81,6 +216,20 @@ impl<'a, D: Display> DisplayWriter<'a, D> {
             display,
             display_text_style: text_style,
             char_cursor: Point::zero(),
+            terminal: None,
+        }
+    }
+
+    /// Creates a writer in terminal mode, which continues where the last writer of the terminal
+    /// left off and scrolls once the box is full.
+    pub fn terminal(
+        display: &'a mut D,
+        text_style: DisplayTextStyle<<D as DrawTarget>::Color>,
+        terminal: &'a mut Terminal,
+    ) -> Self {
+        Self {
+            terminal: Some(terminal),
+            ..Self::new(display, text_style)
         }
     }
 
@@ -96,6 +245,12 @@ impl<'a, D: Display> DisplayWriter<'a, D> {
 }
 impl<D: Display> core::fmt::Write for DisplayWriter<'_, D> {
     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if let Some(terminal) = self.terminal.as_deref_mut() {
+            let size = Terminal::size(&self.display_text_style);
+            terminal.write(s, size);
+            return terminal.redraw(self.display, &self.display_text_style, size);
+        }
+
         // Iterate over lines
         'lines: for (line_num, mut line) in s.lines().enumerate() {
             // Advance to the next line if applicable
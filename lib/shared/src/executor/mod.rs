@@ -0,0 +1,254 @@
+//! Runs futures to completion without RTIC, sleeping between polls until they are woken.
+//!
+//! The waker sets a flag and the executor only sleeps with `wfi` while the flag is clear, with
+//! interrupts masked around the check so that a wake from an interrupt handler cannot slip in
+//! between. The future is polled after every wakeup, whether or not it was woken, which lets
+//! [`block_on_with_stats`] catch drivers that complete without calling the waker.
+//!
+//! Nothing is allocated: several futures are run at once by combining them into one with
+//! [`join!`](crate::join) or [`select!`](crate::select), and [`with_timeout`] races a future
+//! against a [`TimeSource`]:
+//! ```ignore
+//! let clock = SysTickClock::new(syst, 120_000_000);
+//! let (transfer, sent) = block_on(join!(
+//!     with_timeout(&clock, 100, dma_transfer),
+//!     uart.write(b"hello"),
+//! ));
+//! ```
+use core::fmt;
+use core::future::poll_fn;
+use core::pin::pin;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+mod time;
+
+#[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+#[doc(hidden)]
+pub use cortex_m_rt::exception;
+pub use time::{Sleep, TimeSource, Timeout, sleep, with_timeout};
+#[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+pub use time::{SysTickClock, on_systick};
+
+/// Set by the waker. There is a single flag, so only one future can be blocked on at a time.
+static WOKEN: AtomicBool = AtomicBool::new(false);
+
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, wake, wake, drop);
+
+fn clone(_: *const ()) -> RawWaker {
+    RawWaker::new(ptr::null(), &VTABLE)
+}
+
+fn wake(_: *const ()) {
+    WOKEN.store(true, Ordering::Release);
+}
+
+fn drop(_: *const ()) {}
+
+/// How the waker of a future was used while it was blocked on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WakeStats {
+    pub polls: u32,
+    /// Times the waker was called before a poll.
+    pub wakes: u32,
+    /// Times the waker was called but the future was still pending. These are allowed, but each
+    /// one costs a poll.
+    pub spurious: u32,
+    /// Times the future was ready without the waker having been called. A driver that does this
+    /// only completes when some other interrupt happens to end the sleep.
+    pub missed: u32,
+}
+impl fmt::Display for WakeStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} polls, {} wakes, {} spurious, {} missed",
+            self.polls, self.wakes, self.spurious, self.missed
+        )
+    }
+}
+
+/// Takes the wake flag, clearing it.
+fn take_woken() -> bool {
+    // A load and a store rather than a swap, which the Cortex-M0+ lacks, in a critical section so
+    // that a wake from an interrupt cannot land in between and be lost
+    critical_section::with(|_| {
+        let woken = WOKEN.load(Ordering::Acquire);
+        WOKEN.store(false, Ordering::Release);
+        woken
+    })
+}
+
+/// Sleeps until an interrupt, unless the waker has already been called, returning whether it was.
+#[cfg(target_arch = "arm")]
+fn wait_for_interrupt() -> bool {
+    let primask = cortex_m::register::primask::read();
+    cortex_m::interrupt::disable();
+    // A pending interrupt still ends the wait while they are masked, and its handler runs as soon
+    // as they are unmasked again
+    if !WOKEN.load(Ordering::Acquire) {
+        cortex_m::asm::wfi();
+    }
+    if primask.is_active() {
+        unsafe { cortex_m::interrupt::enable() };
+    }
+
+    take_woken()
+}
+
+/// Called instead of sleeping off the target, where there are no interrupts to wait for.
+#[cfg(not(target_arch = "arm"))]
+static IDLE_HOOK: core::sync::atomic::AtomicPtr<()> =
+    core::sync::atomic::AtomicPtr::new(ptr::null_mut());
+
+/// Sets what to do off the target whenever the executor would sleep, such as letting a fake time
+/// source move on to its next deadline.
+#[cfg(not(target_arch = "arm"))]
+pub fn set_idle_hook(hook: fn()) {
+    IDLE_HOOK.store(hook as *mut (), Ordering::Release);
+}
+
+/// Runs the idle hook, if there is one, rather than waiting for an interrupt.
+#[cfg(not(target_arch = "arm"))]
+fn wait_for_interrupt() -> bool {
+    let hook = IDLE_HOOK.load(Ordering::Acquire);
+    if hook.is_null() {
+        core::hint::spin_loop();
+    } else {
+        // SAFETY: Only function pointers are ever stored
+        unsafe { core::mem::transmute::<*mut (), fn()>(hook)() };
+    }
+
+    take_woken()
+}
+
+/// Runs the future to completion, sleeping while it is pending.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    block_on_with_stats(future).0
+}
+
+/// Runs the future to completion like [`block_on`], also counting how its waker was used.
+pub fn block_on_with_stats<F: Future>(future: F) -> (F::Output, WakeStats) {
+    // SAFETY: The vtable functions ignore the data pointer and only touch the static flag
+    let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    let mut stats = WakeStats::default();
+
+    // The first poll needs no wake, but any left over from before does not count for this future
+    take_woken();
+    let mut woken = true;
+
+    loop {
+        stats.polls += 1;
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            if !woken {
+                stats.missed += 1;
+            }
+            return (output, stats);
+        }
+        if woken && stats.polls > 1 {
+            stats.spurious += 1;
+        }
+
+        woken = wait_for_interrupt();
+        if woken {
+            stats.wakes += 1;
+        }
+    }
+}
+
+/// The output of whichever of two futures finished first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Either<A, B> {
+    First(A),
+    Second(B),
+}
+
+/// Runs both futures until they have both finished.
+///
+/// Both are polled whenever either might have made progress, which is every time the executor is
+/// woken. Use [`join!`](crate::join) for more than two.
+pub async fn join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    let (mut a, mut b) = (pin!(a), pin!(b));
+    let (mut output_a, mut output_b) = (None, None);
+
+    poll_fn(|cx| {
+        if output_a.is_none()
+            && let Poll::Ready(output) = a.as_mut().poll(cx)
+        {
+            output_a = Some(output);
+        }
+        if output_b.is_none()
+            && let Poll::Ready(output) = b.as_mut().poll(cx)
+        {
+            output_b = Some(output);
+        }
+
+        if output_a.is_some() && output_b.is_some() {
+            Poll::Ready((output_a.take().unwrap(), output_b.take().unwrap()))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Runs both futures until either has finished, dropping the other one.
+///
+/// The first future is polled first, so it wins if both are ready. Use
+/// [`select!`](crate::select) for more than two.
+pub async fn select<A: Future, B: Future>(a: A, b: B) -> Either<A::Output, B::Output> {
+    let (mut a, mut b) = (pin!(a), pin!(b));
+
+    poll_fn(|cx| {
+        if let Poll::Ready(output) = a.as_mut().poll(cx) {
+            Poll::Ready(Either::First(output))
+        } else if let Poll::Ready(output) = b.as_mut().poll(cx) {
+            Poll::Ready(Either::Second(output))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
+
+/// Combines up to four futures into one that finishes once all of them have, with a tuple of
+/// their outputs.
+#[macro_export]
+macro_rules! join {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::executor::join($a, $b)
+    };
+    ($a:expr, $b:expr, $c:expr $(,)?) => {
+        async {
+            let (a, (b, c)) = $crate::executor::join($a, $crate::executor::join($b, $c)).await;
+            (a, b, c)
+        }
+    };
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {
+        async {
+            let ((a, b), (c, d)) = $crate::executor::join(
+                $crate::executor::join($a, $b),
+                $crate::executor::join($c, $d),
+            )
+            .await;
+            (a, b, c, d)
+        }
+    };
+}
+
+/// Combines futures into one that finishes with the output of whichever finishes first.
+///
+/// With more than two futures the [`Either`]s nest to the right, so that the third of three is
+/// `Either::Second(Either::Second(_))`.
+#[macro_export]
+macro_rules! select {
+    ($a:expr, $b:expr $(,)?) => {
+        $crate::executor::select($a, $b)
+    };
+    ($a:expr, $($rest:expr),+ $(,)?) => {
+        $crate::executor::select($a, $crate::select!($($rest),+))
+    };
+}
@@ -0,0 +1,158 @@
+use super::{Either, select};
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+/// A millisecond clock that can wake the executor at a deadline.
+pub trait TimeSource {
+    /// Milliseconds since some point in the past, wrapping around.
+    fn now_ms(&self) -> u32;
+
+    /// Wakes the waker once [`now_ms`](Self::now_ms) has reached the deadline.
+    ///
+    /// Only the earliest deadline since the last wake has to be kept: the executor polls all of
+    /// its futures when woken, so any later ones ask again.
+    fn wake_at(&self, deadline_ms: u32, waker: &Waker);
+}
+
+/// Whether `now` is at or past `deadline`, allowing for wraparound.
+fn reached(now: u32, deadline: u32) -> bool {
+    now.wrapping_sub(deadline) < 1 << 31
+}
+
+/// A future that finishes at a deadline of a [`TimeSource`].
+pub struct Sleep<'a, T: TimeSource> {
+    time: &'a T,
+    deadline_ms: u32,
+}
+impl<T: TimeSource> Future for Sleep<'_, T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if reached(self.time.now_ms(), self.deadline_ms) {
+            Poll::Ready(())
+        } else {
+            self.time.wake_at(self.deadline_ms, cx.waker());
+            Poll::Pending
+        }
+    }
+}
+
+/// Finishes `ms` milliseconds from now.
+pub fn sleep<T: TimeSource>(time: &T, ms: u32) -> Sleep<'_, T> {
+    Sleep {
+        time,
+        deadline_ms: time.now_ms().wrapping_add(ms),
+    }
+}
+
+/// The future given to [`with_timeout`] did not finish in time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Timeout;
+
+/// Runs the future for at most `ms` milliseconds, dropping it if it has not finished by then.
+pub async fn with_timeout<T: TimeSource, F: Future>(
+    time: &T,
+    ms: u32,
+    future: F,
+) -> Result<F::Output, Timeout> {
+    match select(future, sleep(time, ms)).await {
+        Either::First(output) => Ok(output),
+        Either::Second(()) => Err(Timeout),
+    }
+}
+
+#[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+mod systick {
+    use super::{TimeSource, reached};
+    use core::cell::RefCell;
+    use core::sync::atomic::{AtomicU32, Ordering};
+    use core::task::Waker;
+    use cortex_m::peripheral::{SYST, syst::SystClkSource};
+    use critical_section::Mutex;
+
+    /// Milliseconds counted by the SysTick exception.
+    static TICKS: AtomicU32 = AtomicU32::new(0);
+    /// The earliest deadline that has been asked for, and who to wake then.
+    static ALARM: Mutex<RefCell<Option<(u32, Waker)>>> = Mutex::new(RefCell::new(None));
+
+    /// A [`TimeSource`] that counts milliseconds with the SysTick exception.
+    ///
+    /// This takes over SysTick from the `Delay`, so give the `SYST` back to it when done. It is
+    /// not available with the `systick` feature, where the RTIC monotonic owns the exception.
+    ///
+    /// The exception is left to the program, which binds it with
+    /// [`bind_systick!`](crate::bind_systick) once, so that programs without the clock are free
+    /// to use SysTick in their own way.
+    pub struct SysTickClock {
+        syst: SYST,
+    }
+    impl SysTickClock {
+        /// Starts SysTick with an exception every millisecond of the `cpu_hz` core clock.
+        pub fn new(mut syst: SYST, cpu_hz: u32) -> Self {
+            syst.disable_counter();
+            syst.set_clock_source(SystClkSource::Core);
+            syst.set_reload(cpu_hz / 1000 - 1);
+            syst.clear_current();
+            syst.enable_interrupt();
+            syst.enable_counter();
+
+            Self { syst }
+        }
+
+        pub fn free(mut self) -> SYST {
+            self.syst.disable_interrupt();
+            self.syst.disable_counter();
+            critical_section::with(|cs| ALARM.borrow_ref_mut(cs).take());
+            self.syst
+        }
+    }
+    impl TimeSource for SysTickClock {
+        fn now_ms(&self) -> u32 {
+            TICKS.load(Ordering::Relaxed)
+        }
+
+        fn wake_at(&self, deadline_ms: u32, waker: &Waker) {
+            critical_section::with(|cs| {
+                let mut alarm = ALARM.borrow_ref_mut(cs);
+                match alarm.as_mut() {
+                    Some((deadline, _)) if reached(deadline_ms, *deadline) => {}
+                    _ => *alarm = Some((deadline_ms, waker.clone())),
+                }
+            });
+        }
+    }
+
+    /// Counts a millisecond and wakes the alarm once it is due. This is the SysTick handler of
+    /// [`SysTickClock`], bound by [`bind_systick!`](crate::bind_systick).
+    pub fn on_systick() {
+        // Only this handler writes the count, so there is no need for a read-modify-write, which
+        // the Cortex-M0+ lacks
+        let now = TICKS.load(Ordering::Relaxed).wrapping_add(1);
+        TICKS.store(now, Ordering::Relaxed);
+
+        critical_section::with(|cs| {
+            let mut alarm = ALARM.borrow_ref_mut(cs);
+            if alarm
+                .as_ref()
+                .is_some_and(|(deadline, _)| reached(now, *deadline))
+            {
+                let (_, waker) = alarm.take().unwrap();
+                waker.wake();
+            }
+        });
+    }
+}
+#[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+pub use systick::{SysTickClock, on_systick};
+
+/// Binds the SysTick exception to [`SysTickClock`], for programs that use it.
+#[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+#[macro_export]
+macro_rules! bind_systick {
+    () => {
+        #[$crate::executor::exception]
+        fn SysTick() {
+            $crate::executor::on_systick();
+        }
+    };
+}
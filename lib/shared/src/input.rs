@@ -0,0 +1,230 @@
+//! Button input as debounced press, release and long-press events.
+//!
+//! Boards sample their buttons into a bitmask of [`ButtonId`]s and feed it to a [`Debouncer`],
+//! which times everything by counting samples taken [`POLL_MS`] apart. Blocking programs use
+//! [`Input`], while async tasks use [`AsyncInput`] so that the executor can run other tasks while
+//! they wait.
+use core::future::Future;
+
+/// Milliseconds between samples of the buttons.
+pub const POLL_MS: u32 = 1;
+/// How long a button has to stay the same before the change is reported.
+pub const DEBOUNCE_MS: u32 = 20;
+/// How long a button has to be held before a long press is reported.
+pub const LONG_PRESS_MS: u32 = 800;
+
+/// A button on either board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonId {
+    A,
+    B,
+    /// Only on the Metro M0 display FeatherWing.
+    C,
+    /// Only on the PyGamer.
+    Select,
+    /// Only on the PyGamer.
+    Start,
+}
+impl ButtonId {
+    pub const ALL: [Self; 5] = [Self::A, Self::B, Self::C, Self::Select, Self::Start];
+
+    /// The bit of the button in a sample.
+    pub const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonEvent {
+    Pressed(ButtonId),
+    Released(ButtonId),
+    /// The button has been held for [`LONG_PRESS_MS`], which is reported once per press.
+    LongPress(ButtonId),
+}
+impl ButtonEvent {
+    pub const fn button(self) -> ButtonId {
+        match self {
+            Self::Pressed(b) | Self::Released(b) | Self::LongPress(b) => b,
+        }
+    }
+}
+
+/// Most events that are kept until they are taken.
+const QUEUE_LEN: usize = 8;
+
+/// Turns samples of the buttons into debounced events.
+#[derive(Default)]
+pub struct Debouncer {
+    /// The debounced state of the buttons.
+    state: u8,
+    /// The last sample.
+    sample: u8,
+    /// How long the last sample has stayed the same.
+    stable_ms: u32,
+    /// How long each button has been held, by its index in [`ButtonId::ALL`].
+    held_ms: [u32; ButtonId::ALL.len()],
+    /// Buttons whose long press has been reported.
+    long_pressed: u8,
+    queue: [Option<ButtonEvent>; QUEUE_LEN],
+    head: usize,
+}
+impl Debouncer {
+    pub const fn new() -> Self {
+        Self {
+            state: 0,
+            sample: 0,
+            stable_ms: 0,
+            held_ms: [0; ButtonId::ALL.len()],
+            long_pressed: 0,
+            queue: [None; QUEUE_LEN],
+            head: 0,
+        }
+    }
+
+    /// Whether a button is pressed, after debouncing.
+    pub fn is_pressed(&self, button: ButtonId) -> bool {
+        self.state & button.mask() != 0
+    }
+
+    /// Whether all buttons have been released long enough that nothing more can happen until one
+    /// is pressed again.
+    pub fn is_idle(&self) -> bool {
+        self.state == 0 && self.sample == 0
+    }
+
+    /// Adds a sample of the pressed buttons, taken `elapsed_ms` after the last one.
+    pub fn update(&mut self, sample: u8, elapsed_ms: u32) {
+        if sample == self.sample {
+            self.stable_ms = self.stable_ms.saturating_add(elapsed_ms);
+        } else {
+            self.sample = sample;
+            self.stable_ms = 0;
+        }
+
+        if self.stable_ms >= DEBOUNCE_MS && sample != self.state {
+            let changed = sample ^ self.state;
+            self.state = sample;
+            self.long_pressed &= !changed;
+
+            for (idx, button) in ButtonId::ALL.into_iter().enumerate() {
+                if changed & button.mask() == 0 {
+                    continue;
+                }
+                if sample & button.mask() != 0 {
+                    self.held_ms[idx] = 0;
+                    self.push(ButtonEvent::Pressed(button));
+                } else {
+                    self.push(ButtonEvent::Released(button));
+                }
+            }
+        }
+
+        for (idx, button) in ButtonId::ALL.into_iter().enumerate() {
+            if self.state & !self.long_pressed & button.mask() == 0 {
+                continue;
+            }
+            self.held_ms[idx] = self.held_ms[idx].saturating_add(elapsed_ms);
+            if self.held_ms[idx] >= LONG_PRESS_MS {
+                self.long_pressed |= button.mask();
+                self.push(ButtonEvent::LongPress(button));
+            }
+        }
+    }
+
+    /// Takes the oldest event that has not been taken yet.
+    pub fn next_event(&mut self) -> Option<ButtonEvent> {
+        let event = self.queue[self.head].take()?;
+        self.head = (self.head + 1) % QUEUE_LEN;
+        Some(event)
+    }
+
+    /// Queues an event, dropping it if the queue is full.
+    fn push(&mut self, event: ButtonEvent) {
+        if let Some(slot) = (0..QUEUE_LEN)
+            .map(|i| (self.head + i) % QUEUE_LEN)
+            .find(|&i| self.queue[i].is_none())
+        {
+            self.queue[slot] = Some(event);
+        }
+    }
+}
+
+/// A button press for navigating a menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Nav {
+    Previous,
+    Next,
+    Select,
+}
+
+pub trait Input {
+    /// Describes which buttons do what in a menu.
+    const NAV_HELP: &'static str;
+
+    /// Samples the buttons once, returning the oldest event that has not been taken yet.
+    ///
+    /// This never blocks. Debouncing and long presses are timed by counting calls, so call it
+    /// every [`POLL_MS`], as the waits below do.
+    fn poll_event(&mut self) -> Option<ButtonEvent>;
+
+    /// Waits for [`POLL_MS`] between samples.
+    fn pause(&mut self);
+
+    /// What a button does in a menu, if anything.
+    fn nav(button: ButtonId) -> Option<Nav>;
+
+    fn wait_for_event(&mut self) -> ButtonEvent {
+        loop {
+            if let Some(event) = self.poll_event() {
+                return event;
+            }
+            self.pause();
+        }
+    }
+
+    /// Waits for any button to be pressed, returning which one.
+    fn wait_for_button(&mut self) -> ButtonId {
+        loop {
+            if let ButtonEvent::Pressed(button) = self.wait_for_event() {
+                return button;
+            }
+        }
+    }
+
+    /// Waits for any button to be pressed, giving up after `timeout_ms`.
+    fn wait_for_button_timeout(&mut self, timeout_ms: u32) -> Option<ButtonId> {
+        for _ in 0..timeout_ms.div_ceil(POLL_MS) {
+            if let Some(ButtonEvent::Pressed(button)) = self.poll_event() {
+                return Some(button);
+            }
+            self.pause();
+        }
+
+        None
+    }
+
+    fn wait_for_nav(&mut self) -> Nav {
+        loop {
+            if let Some(nav) = Self::nav(self.wait_for_button()) {
+                return nav;
+            }
+        }
+    }
+}
+
+/// Button input for async tasks, which waits without blocking the executor.
+pub trait AsyncInput {
+    /// Waits for the next debounced event.
+    fn event(&mut self) -> impl Future<Output = ButtonEvent>;
+
+    /// Waits for any button to be pressed, returning which one.
+    fn press(&mut self) -> impl Future<Output = ButtonId> {
+        async {
+            loop {
+                if let ButtonEvent::Pressed(button) = self.event().await {
+                    return button;
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,5 @@
 #![no_std]
 #![feature(iter_advance_by)]
-#![feature(generic_const_exprs)]
-
-use core::future::Future;
 
 #[cfg(all(
     any(feature = "rtic-metro", feature = "rtic-pygamer",),
@@ -16,26 +13,60 @@ compile_error!("Cannot select both clocks.");
 #[cfg(any(feature = "clock1k", feature = "clock32k"))]
 use atsamd_hal::time::Hertz;
 
+pub mod checks;
+pub mod deadlines;
 mod display;
+pub mod executor;
+pub mod input;
+pub mod mono_backend;
 #[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer",))]
 mod monotonic;
+pub mod pages;
+pub mod panic_record;
+pub mod rate_check;
+pub mod registry;
+pub mod results;
+pub mod retained;
+pub mod rtc;
 mod screens;
-#[cfg(any(feature = "metro", feature = "pygamer"))]
+pub mod stats;
+pub mod string;
 pub mod tests;
 
+pub use input::{Input, Nav};
+
 #[cfg(feature = "clock1k")]
 pub const RTC_CLOCK_RATE: Hertz = Hertz::from_raw(1024);
 #[cfg(feature = "clock32k")]
 pub const RTC_CLOCK_RATE: Hertz = Hertz::from_raw(32768);
 
 pub mod prelude {
-    pub use super::Input;
     #[cfg(any(feature = "clock1k", feature = "clock32k"))]
     pub use super::RTC_CLOCK_RATE;
+    #[cfg(all(
+        any(feature = "metro", feature = "pygamer"),
+        not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+    ))]
+    pub use super::bind_rtc_interrupt;
     pub use super::display::*;
+    pub use super::executor::{
+        Either, TimeSource, Timeout, WakeStats, block_on, block_on_with_stats, sleep, with_timeout,
+    };
+    pub use super::input::{AsyncInput, ButtonEvent, ButtonId, Input, Nav};
+    pub use super::mono_backend::MonoBackend;
     #[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer",))]
-    pub use super::monotonic::{Mono, display_monotonic_info};
-    pub use super::{block_on, screens::ScreensGen};
+    pub use super::monotonic::{MONO_TICK_HZ, Mono, display_monotonic_info, systick_check};
+    pub use super::pages::PagedWriter;
+    pub use super::panic_record::{PanicRecord, record_panic};
+    pub use super::registry::HwTest;
+    pub use super::results::{Outcome, emit_results, set_result_sink};
+    #[cfg(any(feature = "metro", feature = "pygamer"))]
+    pub use super::rtc::RetainedClock;
+    pub use super::screens::ScreensGen;
+    pub use super::tests::RtcReset;
+    #[cfg(all(any(feature = "metro", feature = "pygamer"), not(feature = "systick")))]
+    pub use super::{bind_systick, executor::SysTickClock};
+    pub use super::{check, check_eq, check_range, join, select};
     #[cfg(feature = "metro")]
     pub use metro_m0::{self as bsp, hal, pac};
     #[cfg(feature = "pygamer")]
@@ -51,27 +82,6 @@ pub mod prelude {
     pub use pac::{CorePeripherals, Peripherals};
     #[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
     pub use rtic;
-}
-pub trait Input {
-    fn wait_for_button(&mut self);
-}
-
-pub fn block_on<F: Future>(mut future: F) -> F::Output {
-    use core::pin::Pin;
-    use core::task::{Context, Poll};
-    use futures_task::noop_waker;
-
-    let waker = noop_waker();
-    let mut cx = Context::from_waker(&waker);
-    let mut pinned = unsafe { Pin::new_unchecked(&mut future) };
-
-    loop {
-        match pinned.as_mut().poll(&mut cx) {
-            Poll::Ready(val) => return val,
-            Poll::Pending => {
-                // Wait for an interrupt
-                //cortex_m::asm::wfi();
-            }
-        }
-    }
+    #[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
+    pub use shared_macros::stress_tasks;
 }
@@ -0,0 +1,91 @@
+//! The monotonics that RTIC programs run on, and picking one at boot in programs built with the
+//! `mono-select` feature, which have both.
+//!
+//! The pick is kept across resets, so that after the first boot a program starts straight away
+//! with the same monotonic unless a button is pressed.
+use crate::{Input, display::Display, retained::Retained, screens::ScreensGen};
+use core::fmt::Write;
+
+/// How long a stored pick waits for a button press to show the menu instead.
+pub const SELECT_TIMEOUT_MS: u32 = 2000;
+
+/// A monotonic for RTIC.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MonoBackend {
+    /// The SysTick timer of the core, at 200 Hz.
+    SysTick,
+    /// The RTC, at the rate of its clock.
+    Rtc,
+}
+impl MonoBackend {
+    pub const ALL: [Self; 2] = [Self::SysTick, Self::Rtc];
+
+    /// How many times a second the monotonic ticks.
+    pub const fn tick_hz(self) -> u32 {
+        match self {
+            Self::SysTick => 200,
+            Self::Rtc if cfg!(feature = "clock32k") => 32768,
+            Self::Rtc => 1024,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SysTick => "SysTick",
+            Self::Rtc if cfg!(feature = "clock32k") => "RTC 32 kHz",
+            Self::Rtc => "RTC 1 kHz",
+        }
+    }
+
+    /// Takes the pick stored before the last reset, if any.
+    pub fn take_stored() -> Option<Self> {
+        STORED.take()
+    }
+
+    /// Stores the pick for after the next reset.
+    pub fn store(self) {
+        STORED.store(self);
+    }
+}
+
+#[cfg_attr(
+    any(feature = "metro", feature = "pygamer"),
+    unsafe(link_section = ".uninit.MONO_BACKEND")
+)]
+static STORED: Retained<MonoBackend> = Retained::new();
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Picks the monotonic to start, which is the `stored` one unless a button is pressed within
+    /// [`SELECT_TIMEOUT_MS`], and otherwise comes from a menu.
+    pub fn pick_mono_backend(&mut self, stored: Option<MonoBackend>) -> MonoBackend {
+        if let Some(backend) = stored {
+            let mut writer = self.new_screen();
+            writeln!(writer, "Monotonic: {}", backend.name()).unwrap();
+            writeln!(writer, "Press a button to change").unwrap();
+            writer.flush();
+
+            if self
+                .input()
+                .wait_for_button_timeout(SELECT_TIMEOUT_MS)
+                .is_none()
+            {
+                return backend;
+            }
+        }
+
+        let backends = MonoBackend::ALL;
+        backends[self.menu("Select a monotonic:", &backends, |b| b.name())]
+    }
+
+    /// Picks the monotonic to start with [`pick_mono_backend`](Self::pick_mono_backend), from
+    /// the one stored before the last reset, and stores the pick for the next.
+    pub fn select_mono_backend(&mut self) -> MonoBackend {
+        let backend = self.pick_mono_backend(MonoBackend::take_stored());
+        backend.store();
+        self.new_screen().flush();
+        backend
+    }
+}
@@ -1,8 +1,16 @@
 use crate::display::{Display, DisplayTextStyle, DisplayWriter};
+use crate::mono_backend::MonoBackend;
 use crate::prelude::pac;
-use atsamd_hal::prelude::*;
+use crate::rate_check::RateCheck;
+#[cfg(feature = "systick")]
+use crate::rate_check::{SysTickStopwatch, check_rate};
+use crate::rtc::RawCounter;
+use crate::tests::Stopwatch;
 #[cfg(any(feature = "clock1k", feature = "clock32k"))]
 use atsamd_hal::{fugit::ExtU64, rtc::rtic::rtc_clock, rtc_monotonic};
+use atsamd_hal::{prelude::*, time::Hertz};
+#[cfg(feature = "systick")]
+use core::cell::{Cell, RefCell};
 use core::fmt::Write;
 use core::future::Future;
 use embedded_graphics::{mono_font, prelude::*, text};
@@ -15,22 +23,39 @@ compile_error!("A clock rate feature or systick must be specified");
 #[cfg(not(any(feature = "metro", feature = "pygamer")))]
 compile_error!("A platform feature must be specified");
 
+#[cfg(all(
+    feature = "mono-select",
+    not(any(feature = "clock1k", feature = "clock32k"))
+))]
+compile_error!("Selecting the monotonic at boot needs a clock rate feature for the RTC");
+
 #[cfg(feature = "clock1k")]
 type _ClockRate = rtc_clock::Clock1k;
 #[cfg(feature = "clock32k")]
 type _ClockRate = rtc_clock::Clock32k;
 
-#[cfg(feature = "systick")]
+/// How many times a second the ticks of [`Mono::now`] count, which with `mono-select` is in
+/// microseconds whichever monotonic runs.
+#[cfg(not(feature = "mono-select"))]
+pub const MONO_TICK_HZ: u32 = Mono::backend().tick_hz();
+#[cfg(feature = "mono-select")]
+pub const MONO_TICK_HZ: u32 = 1_000_000;
+
+#[cfg(all(feature = "systick", not(feature = "mono-select")))]
 rtic_monotonics::systick_monotonic!(Mono, 200);
 #[cfg(not(feature = "systick"))]
 rtc_monotonic!(Mono, _ClockRate);
 
-#[cfg(feature = "systick")]
+#[cfg(all(feature = "systick", not(feature = "mono-select")))]
 impl Mono {
     #[inline]
     pub fn delay_ms(delay: u32) -> impl Future<Output = ()> {
         Self::delay(delay.millis())
     }
+
+    pub const fn backend() -> MonoBackend {
+        MonoBackend::SysTick
+    }
 }
 
 #[cfg(not(feature = "systick"))]
@@ -39,46 +64,203 @@ impl Mono {
     pub fn delay_ms(delay: u32) -> impl Future<Output = ()> {
         Self::delay(u64::from(delay).millis())
     }
+
+    pub const fn backend() -> MonoBackend {
+        MonoBackend::Rtc
+    }
 }
 
+#[cfg(not(feature = "mono-select"))]
 impl Mono {
-    pub fn general_start(_syst: crate::prelude::pac::SYST, _rtc: pac::Rtc) {
+    /// Starts the monotonic. SysTick counts the core clock at `sysclk`, which is checked against
+    /// the RTC first.
+    pub fn general_start(_syst: pac::SYST, _rtc: pac::Rtc, _sysclk: Hertz) {
         #[cfg(feature = "systick")]
-        Mono::start(_syst, 120_000_000);
+        {
+            let syst = check_systick(_syst, _rtc, _sysclk);
+            Mono::start(syst, _sysclk.to_Hz());
+        }
         #[cfg(not(feature = "systick"))]
         Mono::start(_rtc);
     }
 }
 
-pub fn display_monotonic_info<D: Display>(display: &mut D) {
-    let style = DisplayTextStyle::new(
-        Point::new(
-            (display.size().width - D::FONT.character_size.width * 9) as i32,
-            display.size().height as i32,
-        ),
-        None,
-        // Use inverted colors here
-        mono_font::MonoTextStyleBuilder::new()
-            .font(&D::FONT)
-            .text_color(D::BACKGROUND_COLOR)
-            .background_color(D::TEXT_COLOR)
-            .build(),
-        text::TextStyleBuilder::new()
-            .baseline(text::Baseline::Bottom)
-            .build(),
+/// The check of SysTick against the RTC, once it has been made.
+#[cfg(feature = "systick")]
+static SYSTICK_CHECK: critical_section::Mutex<Cell<Option<RateCheck>>> =
+    critical_section::Mutex::new(Cell::new(None));
+
+/// The RTC, which SysTick as the monotonic leaves counting once it has been checked against it.
+#[cfg(feature = "systick")]
+static RTC_COUNTER: critical_section::Mutex<RefCell<Option<RawCounter>>> =
+    critical_section::Mutex::new(RefCell::new(None));
+
+/// Counts one second of the RTC with SysTick before it is started as the monotonic, keeping the
+/// result for [`display_monotonic_info`] and the RTC for [`take_rtc_stopwatch`].
+#[cfg(feature = "systick")]
+fn check_systick(syst: pac::SYST, rtc: pac::Rtc, sysclk: Hertz) -> pac::SYST {
+    let counter = RawCounter::new(rtc);
+    let mut stopwatch = SysTickStopwatch::new(syst, sysclk.to_Hz());
+    let check = check_rate(
+        &mut stopwatch,
+        || counter.count(),
+        MonoBackend::Rtc.tick_hz(),
     );
+    critical_section::with(|cs| {
+        SYSTICK_CHECK.borrow(cs).set(Some(check));
+        RTC_COUNTER.borrow_ref_mut(cs).replace(counter);
+    });
+    stopwatch.free()
+}
+
+/// How SysTick compared with the RTC, if it was started as the monotonic.
+pub fn systick_check() -> Option<RateCheck> {
+    #[cfg(feature = "systick")]
+    return critical_section::with(|cs| SYSTICK_CHECK.borrow(cs).get());
+    #[cfg(not(feature = "systick"))]
+    None
+}
+
+/// Times with the RTC, for a monotonic that does not use it.
+pub struct RtcStopwatch(RawCounter);
+impl Stopwatch for RtcStopwatch {
+    fn rate_hz(&self) -> u32 {
+        MonoBackend::Rtc.tick_hz()
+    }
+
+    fn ticks(&mut self) -> u32 {
+        self.0.count()
+    }
 
-    let mut writer = DisplayWriter::new(display, style);
+    fn uses_rtc(&self) -> bool {
+        true
+    }
+}
 
+/// Takes the RTC to time the monotonic against, which is only free if SysTick was started as
+/// the monotonic.
+pub fn take_rtc_stopwatch() -> Option<RtcStopwatch> {
     #[cfg(feature = "systick")]
-    write!(writer, "systick ").unwrap();
-    #[cfg(all(not(feature = "systick"), feature = "metro"))]
-    write!(writer, "mode1 ").unwrap();
-    #[cfg(all(not(feature = "systick"), feature = "pygamer"))]
-    write!(writer, "mode0 ").unwrap();
-
-    #[cfg(feature = "clock1k")]
-    write!(writer, " 1k").unwrap();
-    #[cfg(feature = "clock32k")]
-    write!(writer, "32k").unwrap();
+    return critical_section::with(|cs| RTC_COUNTER.borrow_ref_mut(cs).take()).map(RtcStopwatch);
+    #[cfg(not(feature = "systick"))]
+    None
+}
+
+#[cfg(feature = "mono-select")]
+pub use select::Mono;
+
+/// Both monotonics, with one of them picked at boot.
+#[cfg(feature = "mono-select")]
+mod select {
+    use super::*;
+    use critical_section::Mutex;
+
+    rtic_monotonics::systick_monotonic!(SysTickMono, 200);
+    rtc_monotonic!(RtcMono, _ClockRate);
+
+    /// The instants of [`Mono`], in microseconds.
+    pub type Instant = fugit::Instant<u64, 1, 1_000_000>;
+
+    static BACKEND: Mutex<Cell<MonoBackend>> = Mutex::new(Cell::new(MonoBackend::SysTick));
+
+    /// Whichever of the monotonics was started.
+    pub struct Mono;
+    impl Mono {
+        /// Starts the monotonic picked by `backend`, leaving the other one alone. SysTick
+        /// counts the core clock at `sysclk`, which is checked against the RTC first.
+        pub fn select_start(backend: MonoBackend, syst: pac::SYST, rtc: pac::Rtc, sysclk: Hertz) {
+            critical_section::with(|cs| BACKEND.borrow(cs).set(backend));
+            match backend {
+                MonoBackend::SysTick => {
+                    let syst = check_systick(syst, rtc, sysclk);
+                    SysTickMono::start(syst, sysclk.to_Hz());
+                }
+                MonoBackend::Rtc => RtcMono::start(rtc),
+            }
+        }
+
+        /// Starts the monotonic that was picked before the last reset, or else SysTick.
+        pub fn general_start(syst: pac::SYST, rtc: pac::Rtc, sysclk: Hertz) {
+            let backend = MonoBackend::take_stored().unwrap_or(MonoBackend::SysTick);
+            backend.store();
+            Self::select_start(backend, syst, rtc, sysclk);
+        }
+
+        pub fn backend() -> MonoBackend {
+            critical_section::with(|cs| BACKEND.borrow(cs).get())
+        }
+
+        pub fn now() -> Instant {
+            let micros = match Self::backend() {
+                MonoBackend::SysTick => {
+                    u64::from(SysTickMono::now().ticks()) * 1_000_000
+                        / u64::from(MonoBackend::SysTick.tick_hz())
+                }
+                MonoBackend::Rtc => RtcMono::now().duration_since_epoch().to_micros(),
+            };
+            Instant::from_ticks(micros)
+        }
+
+        #[inline]
+        pub async fn delay_ms(delay: u32) {
+            match Self::backend() {
+                MonoBackend::SysTick => SysTickMono::delay(delay.millis()).await,
+                MonoBackend::Rtc => RtcMono::delay(u64::from(delay).millis()).await,
+            }
+        }
+    }
+}
+
+pub fn display_monotonic_info<D: Display>(display: &mut D) {
+    let style = |position| {
+        DisplayTextStyle::new(
+            position,
+            None,
+            // Use inverted colors here
+            mono_font::MonoTextStyleBuilder::new()
+                .font(&D::FONT)
+                .text_color(D::BACKGROUND_COLOR)
+                .background_color(D::TEXT_COLOR)
+                .build(),
+            text::TextStyleBuilder::new()
+                .baseline(text::Baseline::Bottom)
+                .build(),
+        )
+    };
+    let size = display.size();
+
+    let mut writer = DisplayWriter::new(
+        display,
+        style(Point::new(
+            (size.width - D::FONT.character_size.width * 9) as i32,
+            size.height as i32,
+        )),
+    );
+
+    match Mono::backend() {
+        MonoBackend::SysTick => write!(writer, "systick ").unwrap(),
+        MonoBackend::Rtc => {
+            #[cfg(feature = "metro")]
+            write!(writer, "mode1 ").unwrap();
+            #[cfg(feature = "pygamer")]
+            write!(writer, "mode0 ").unwrap();
+
+            #[cfg(feature = "clock1k")]
+            write!(writer, " 1k").unwrap();
+            #[cfg(feature = "clock32k")]
+            write!(writer, "32k").unwrap();
+        }
+    }
+
+    // Flag a SysTick that does not count at the rate it was started with, on the line above
+    if let Some(check) = systick_check().filter(|check| !check.passed()) {
+        let line = Point::new(0, (size.height - D::FONT.character_size.height) as i32);
+        write!(
+            DisplayWriter::new(display, style(line)),
+            "SysTick {} MHz, not {}",
+            check.measured_hz / 1_000_000,
+            check.expected_hz / 1_000_000
+        )
+        .unwrap();
+    }
 }
@@ -0,0 +1,101 @@
+//! Output that is too long for one screen, shown a page at a time.
+//!
+//! Format the text into a [`PagedWriter`] and show it with [`ScreensGen::show_pages`]. The pages
+//! are flipped with the menu buttons of the [`Input`]: previous and next step through them, and
+//! select or next on the last page continue.
+use crate::{
+    Input, Nav, display::Display, display::DisplayWriter, screens::ScreensGen, string::FixedString,
+};
+use core::fmt::{self, Write};
+
+/// Formatted text buffered for [`ScreensGen::show_pages`].
+///
+/// Text beyond the capacity is dropped, which is marked at the end of the last page.
+pub struct PagedWriter<const N: usize = 1024>(FixedString<N>);
+impl<const N: usize> PagedWriter<N> {
+    pub const fn new() -> Self {
+        Self(FixedString::new())
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Whether any text was dropped for lack of space.
+    pub fn truncated(&self) -> bool {
+        self.0.truncated()
+    }
+}
+impl<const N: usize> Default for PagedWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> Write for PagedWriter<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write_str(s)
+    }
+}
+
+/// Splits text into the lines shown on the screen, wrapping those longer than `columns`.
+fn screen_lines(text: &str, columns: usize) -> impl Iterator<Item = &str> {
+    text.lines().flat_map(move |mut line| {
+        let mut first = true;
+        core::iter::from_fn(move || {
+            if line.is_empty() && !first {
+                return None;
+            }
+            first = false;
+            let idx = line
+                .char_indices()
+                .nth(columns)
+                .map_or(line.len(), |(idx, _)| idx);
+            let (screen_line, rest) = line.split_at(idx);
+            line = rest;
+            Some(screen_line)
+        })
+    })
+}
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Shows the text a page at a time with "page n/m" in the footer, returning a new screen once
+    /// the user continues.
+    pub fn show_pages<const N: usize>(&mut self, text: &PagedWriter<N>) -> DisplayWriter<'_, D> {
+        let size = self.display().size();
+        let char_size = D::FONT.character_size;
+        let columns = (size.width / char_size.width) as usize;
+        // Leave room for the footer
+        let rows = ((size.height / char_size.height) as usize)
+            .saturating_sub(1)
+            .max(1);
+
+        let marker = if text.truncated() { Some("...") } else { None };
+        let lines = || screen_lines(text.as_str(), columns).chain(marker);
+        let pages = lines().count().div_ceil(rows).max(1);
+        let mut page = 0;
+
+        loop {
+            let mut writer = self.new_screen();
+            for line in lines().skip(page * rows).take(rows) {
+                write!(writer, "{line}").unwrap();
+                // Full lines already moved the cursor on to the next one
+                if line.chars().count() < columns {
+                    writeln!(writer).unwrap();
+                }
+            }
+            self.footer(format_args!("page {}/{pages}", page + 1));
+            self.display().flush();
+
+            match self.input().wait_for_nav() {
+                Nav::Previous => page = page.saturating_sub(1),
+                Nav::Next if page + 1 < pages => page += 1,
+                Nav::Next | Nav::Select => break,
+            }
+        }
+
+        self.new_screen()
+    }
+}
@@ -0,0 +1,166 @@
+//! The last panic, kept in RAM across the reset that follows it, so that it is not lost when the
+//! display is broken or the board is reset before anyone reads the panic screen.
+//!
+//! The board panic handlers record the panic before doing anything else. On the next boot the
+//! board `SetupPackage` takes it and shows it, and once a result sink is set it is also emitted
+//! as a `fault` record.
+use crate::{
+    Input, display::Display, retained::Retained, screens::ScreensGen, string::FixedString,
+};
+use core::cell::Cell;
+use core::fmt::{self, Write};
+use core::panic::Location;
+use critical_section::Mutex;
+
+/// How much of the location is kept, which is enough for the end of most paths.
+pub const LOCATION_LEN: usize = 64;
+/// How much of the message is kept.
+pub const MESSAGE_LEN: usize = 192;
+/// How long the previous panic is shown at boot, unless a button is pressed.
+pub const SHOW_TIMEOUT_MS: u32 = 5000;
+
+/// A panic, with a count of the panics since power on.
+#[derive(Clone, Copy)]
+pub struct PanicRecord {
+    location: FixedString<LOCATION_LEN>,
+    message: FixedString<MESSAGE_LEN>,
+    faults: u32,
+}
+impl PanicRecord {
+    /// Records the panic, keeping the end of the file name of a long location rather than its
+    /// line and column.
+    pub fn new(message: impl fmt::Display, location: Option<&Location>, faults: u32) -> Self {
+        let mut record = Self {
+            location: FixedString::new(),
+            message: FixedString::new(),
+            faults,
+        };
+
+        if let Some(location) = location {
+            let file = location.file();
+            // Room for the marker and the widest line and column
+            let room = LOCATION_LEN - 2 - 2 * 11;
+            let mut start = file.len().saturating_sub(room);
+            while !file.is_char_boundary(start) {
+                start += 1;
+            }
+            let marker = if start > 0 { ".." } else { "" };
+            write!(
+                record.location,
+                "{marker}{}:{}:{}",
+                &file[start..],
+                location.line(),
+                location.column()
+            )
+            .ok();
+        }
+        write!(record.message, "{message}").ok();
+
+        record
+    }
+
+    pub fn message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    /// Where the panic happened, as `file:line:column`.
+    pub fn location(&self) -> Option<&str> {
+        Some(self.location.as_str()).filter(|location| !location.is_empty())
+    }
+
+    /// How many times the board has panicked since it was powered on, counting this panic.
+    pub fn faults(&self) -> u32 {
+        self.faults
+    }
+
+    /// Records a panic for after the next reset, counting it as one more fault.
+    pub fn store(message: impl fmt::Display, location: Option<&Location>) {
+        let faults = FAULTS.take().unwrap_or(0).saturating_add(1);
+        FAULTS.store(faults);
+        LAST.store(Self::new(message, location, faults));
+    }
+
+    /// Takes the panic from before the last reset, if there was one, keeping it for
+    /// [`previous`](Self::previous).
+    pub fn take_previous() -> Option<Self> {
+        let record = LAST.take()?;
+        critical_section::with(|cs| PREVIOUS.borrow(cs).set(Some(record)));
+        Some(record)
+    }
+
+    /// The panic from before the last reset, once it has been taken.
+    pub fn previous() -> Option<Self> {
+        critical_section::with(|cs| PREVIOUS.borrow(cs).get())
+    }
+}
+/// Formats the panic as the panic handler does.
+impl fmt::Display for PanicRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("panicked")?;
+        if let Some(location) = self.location() {
+            write!(f, " at {location}")?;
+        }
+        write!(f, ":\n{}", self.message())?;
+        if self.message.truncated() {
+            f.write_str("...")?;
+        }
+        Ok(())
+    }
+}
+impl fmt::Debug for PanicRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PanicRecord")
+            .field("location", &self.location())
+            .field("message", &self.message())
+            .field("faults", &self.faults)
+            .finish()
+    }
+}
+
+/// Records the panic for after the next reset, for the panic handlers.
+pub fn record_panic(info: &core::panic::PanicInfo) {
+    PanicRecord::store(info.message(), info.location());
+}
+
+#[cfg_attr(
+    any(feature = "metro", feature = "pygamer"),
+    unsafe(link_section = ".uninit.PANIC_RECORD")
+)]
+static LAST: Retained<PanicRecord> = Retained::new();
+
+/// The count of panics, which unlike the panic itself is never taken, so it is kept until power
+/// off.
+#[cfg_attr(
+    any(feature = "metro", feature = "pygamer"),
+    unsafe(link_section = ".uninit.PANIC_FAULTS")
+)]
+static FAULTS: Retained<u32> = Retained::new();
+
+static PREVIOUS: Mutex<Cell<Option<PanicRecord>>> = Mutex::new(Cell::new(None));
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Shows the panic until a button is pressed or [`SHOW_TIMEOUT_MS`] has passed.
+    pub fn show_panic(&mut self, record: &PanicRecord) {
+        let mut writer = self.new_screen();
+        writeln!(writer, "previous run panicked: {}", record.message()).unwrap();
+        if let Some(location) = record.location() {
+            writeln!(writer, "at {location}").unwrap();
+        }
+        self.footer(format_args!("fault {} since power on", record.faults()));
+        self.display().flush();
+
+        self.input().wait_for_button_timeout(SHOW_TIMEOUT_MS);
+        self.new_screen().flush();
+    }
+
+    /// Takes the panic from before the last reset and shows it with
+    /// [`show_panic`](Self::show_panic), if there was one.
+    pub fn show_previous_panic(&mut self) -> Option<PanicRecord> {
+        let record = PanicRecord::take_previous()?;
+        self.show_panic(&record);
+        Some(record)
+    }
+}
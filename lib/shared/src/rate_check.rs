@@ -0,0 +1,109 @@
+//! Checks that a clock runs at the rate it is thought to, by counting it over one second of the
+//! RTC.
+use crate::tests::Stopwatch;
+
+/// How far the measured rate may be from the expected one, in parts per million.
+///
+/// This is loose enough for the internal oscillator that clocks the RTC on the PyGamer, but still
+/// catches a core clock that is wrong altogether, such as 120 MHz on the 48 MHz Metro M0.
+pub const MAX_RATE_ERROR_PPM: i64 = 50_000;
+
+/// The rate a clock was expected to run at, and the rate it was measured at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateCheck {
+    pub expected_hz: u32,
+    pub measured_hz: u32,
+}
+impl RateCheck {
+    /// How far the measured rate is from the expected one, positive when it runs fast.
+    pub fn error_ppm(&self) -> i64 {
+        (i64::from(self.measured_hz) - i64::from(self.expected_hz)) * 1_000_000
+            / i64::from(self.expected_hz)
+    }
+
+    pub fn passed(&self) -> bool {
+        self.error_ppm().abs() <= MAX_RATE_ERROR_PPM
+    }
+}
+
+/// Counts the ticks of the stopwatch over one second of the RTC, which counts at `rtc_hz` and
+/// is read by `rtc_count`, and compares them with the rate that the stopwatch claims.
+///
+/// The second starts as the RTC ticks, so that it is whole. The stopwatch is read all the while,
+/// for one that has to be read often to keep up with its wraparound.
+pub fn check_rate<S: Stopwatch>(
+    stopwatch: &mut S,
+    mut rtc_count: impl FnMut() -> u32,
+    rtc_hz: u32,
+) -> RateCheck {
+    let first = rtc_count();
+    let mut start = first;
+    while start == first {
+        start = rtc_count();
+    }
+
+    let start_ticks = stopwatch.ticks();
+    while rtc_count().wrapping_sub(start) < rtc_hz {
+        stopwatch.ticks();
+    }
+    let measured_hz = stopwatch.ticks().wrapping_sub(start_ticks);
+
+    RateCheck {
+        expected_hz: stopwatch.rate_hz(),
+        measured_hz,
+    }
+}
+
+/// Counts the core clock with SysTick, extending its 24-bit count down into a 32-bit count up.
+///
+/// SysTick wraps around every 2^24 cycles, so read it at least that often.
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+pub struct SysTickStopwatch {
+    syst: cortex_m::peripheral::SYST,
+    rate_hz: u32,
+    last: u32,
+    ticks: u32,
+}
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+impl SysTickStopwatch {
+    const RELOAD: u32 = 0xFF_FFFF;
+
+    /// Starts SysTick counting the core clock, which is thought to run at `rate_hz`.
+    pub fn new(mut syst: cortex_m::peripheral::SYST, rate_hz: u32) -> Self {
+        use cortex_m::peripheral::syst::SystClkSource;
+
+        syst.disable_interrupt();
+        syst.disable_counter();
+        syst.set_clock_source(SystClkSource::Core);
+        syst.set_reload(Self::RELOAD);
+        syst.clear_current();
+        syst.enable_counter();
+
+        Self {
+            syst,
+            rate_hz,
+            last: cortex_m::peripheral::SYST::get_current(),
+            ticks: 0,
+        }
+    }
+
+    pub fn free(mut self) -> cortex_m::peripheral::SYST {
+        self.syst.disable_counter();
+        self.syst
+    }
+}
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+impl Stopwatch for SysTickStopwatch {
+    fn rate_hz(&self) -> u32 {
+        self.rate_hz
+    }
+
+    fn ticks(&mut self) -> u32 {
+        let current = cortex_m::peripheral::SYST::get_current();
+        // The count goes down, and wraps from 0 back to the reload value
+        let elapsed = self.last.wrapping_sub(current) & Self::RELOAD;
+        self.last = current;
+        self.ticks = self.ticks.wrapping_add(elapsed);
+        self.ticks
+    }
+}
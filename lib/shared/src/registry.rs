@@ -0,0 +1,75 @@
+//! A registry of tests that are picked from a menu, so that one firmware image can run them all.
+//!
+//! Tests are registered in a const array of [`HwTest`], each of which runs with mutable access to
+//! a context that holds whatever peripherals the tests need:
+//! ```ignore
+//! const TESTS: &[HwTest<DisplayDriver, Buttons, Context>] = &[
+//!     HwTest::new("rtc", |screens, ctx| { ... }),
+//!     HwTest::new("dsu", |screens, ctx| { ... }),
+//! ];
+//!
+//! Screens::new(pkg.display, pkg.buttons).test_menu(TESTS, &mut context);
+//! ```
+use crate::{Input, Nav, display::Display, screens::ScreensGen};
+use core::fmt::Write;
+
+/// A test that can be run from the menu.
+pub struct HwTest<D: Display + 'static, I: 'static, C: 'static> {
+    pub name: &'static str,
+    pub run: fn(&mut ScreensGen<D, I>, &mut C),
+}
+impl<D: Display, I, C> HwTest<D, I, C> {
+    pub const fn new(name: &'static str, run: fn(&mut ScreensGen<D, I>, &mut C)) -> Self {
+        Self { name, run }
+    }
+}
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Shows a menu of items and lets the user pick one, returning its index.
+    pub fn menu<T>(&mut self, title: &str, items: &[T], name: impl Fn(&T) -> &str) -> usize {
+        // Leave room for the title and the footer
+        let height = self.display().size().height;
+        let rows = (height / D::FONT.character_size.height).saturating_sub(2) as usize;
+        let rows = rows.max(1);
+        let mut selected: usize = 0;
+
+        loop {
+            let first = selected.saturating_sub(rows - 1);
+            let mut writer = self.new_screen();
+            writeln!(writer, "{title}").unwrap();
+            for (idx, item) in items.iter().enumerate().skip(first).take(rows) {
+                let marker = if idx == selected { '>' } else { ' ' };
+                writeln!(writer, "{marker} {}", name(item)).unwrap();
+            }
+            self.footer(I::NAV_HELP);
+            self.display().flush();
+
+            match self.input().wait_for_nav() {
+                Nav::Previous => selected = selected.checked_sub(1).unwrap_or(items.len() - 1),
+                Nav::Next => selected = (selected + 1) % items.len(),
+                Nav::Select => return selected,
+            }
+        }
+    }
+
+    /// Lets the user pick a test from the menu and runs it, returning once the user has seen
+    /// the result.
+    pub fn run_test_from_menu<C>(&mut self, tests: &[HwTest<D, I, C>], context: &mut C) {
+        let test = &tests[self.menu("Select a test:", tests, |t| t.name)];
+
+        self.start_test(test.name);
+        (test.run)(self, context);
+        let _ = self.end_test();
+        let _ = self.wait_for_button();
+    }
+
+    /// Runs tests picked from the menu forever.
+    pub fn test_menu<C>(mut self, tests: &[HwTest<D, I, C>], context: &mut C) -> ! {
+        loop {
+            self.run_test_from_menu(tests, context);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+//! Machine-readable test results.
+//!
+//! Results are emitted as a line-oriented text protocol so that they can be mixed with other
+//! serial output and still be picked out by the host. Every record is a single line terminated
+//! by `\r\n` that starts with [`PREFIX`], followed by the record kind and its fields, all
+//! separated by single spaces:
+//!
+//! | Record                              | Meaning                                                 |
+//! |-------------------------------------|---------------------------------------------------------|
+//! | `@atr hello <version>`              | The device started, with [`PROTOCOL_VERSION`].          |
+//! | `@atr test <name>`                  | A test started, which the following records belong to.  |
+//! | `@atr case <name> <outcome> [<msg>]`| A test case finished as `pass`, `fail` or `skip`.       |
+//! | `@atr value <name> <value>`         | A measured value of the last case, or of the test itself if there is no case yet. |
+//! | `@atr panic <message>`              | The device panicked.                                    |
+//! | `@atr fault <count> <message>`      | The device panicked before its last reset, the `<count>`th time since power on. |
+//! | `@atr end`                          | The current test finished.                              |
+//!
+//! Within fields, a backslash is escaped as `\\`, a space as `\s`, a carriage return as `\r`
+//! and a line feed as `\n`, so that fields never contain separators or line breaks.
+use crate::panic_record::PanicRecord;
+use core::cell::RefCell;
+use core::fmt::{Display, Write};
+
+pub const PREFIX: &str = "@atr";
+pub const PROTOCOL_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+impl Outcome {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Fail => "fail",
+            Self::Skip => "skip",
+        }
+    }
+}
+
+/// Escapes everything written through it as a protocol field.
+struct FieldWriter<'a, W>(&'a mut W);
+impl<W: Write> Write for FieldWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        // Write unescaped runs in one go
+        let mut start = 0;
+        for (idx, c) in s.char_indices() {
+            let escaped = match c {
+                '\\' => "\\\\",
+                ' ' => "\\s",
+                '\r' => "\\r",
+                '\n' => "\\n",
+                _ => continue,
+            };
+            self.0.write_str(&s[start..idx])?;
+            self.0.write_str(escaped)?;
+            start = idx + c.len_utf8();
+        }
+
+        self.0.write_str(&s[start..])
+    }
+}
+
+/// Encodes result records to any text output, such as a USB serial port.
+pub struct ResultWriter<W> {
+    output: W,
+}
+impl<W: Write> ResultWriter<W> {
+    pub fn new(output: W) -> Self {
+        Self { output }
+    }
+
+    pub fn free(self) -> W {
+        self.output
+    }
+
+    fn record(
+        &mut self,
+        kind: &str,
+        fields: &[&dyn Display],
+        message: Option<&dyn Display>,
+    ) -> core::fmt::Result {
+        write!(self.output, "{PREFIX} {kind}")?;
+        for field in fields.iter().chain(message.as_slice()) {
+            self.output.write_char(' ')?;
+            write!(FieldWriter(&mut self.output), "{field}")?;
+        }
+        self.output.write_str("\r\n")
+    }
+
+    pub fn hello(&mut self) -> core::fmt::Result {
+        self.record("hello", &[&PROTOCOL_VERSION], None)
+    }
+
+    pub fn test(&mut self, name: &str) -> core::fmt::Result {
+        self.record("test", &[&name], None)
+    }
+
+    pub fn case(
+        &mut self,
+        name: &str,
+        outcome: Outcome,
+        message: Option<&dyn Display>,
+    ) -> core::fmt::Result {
+        self.record("case", &[&name, &outcome.as_str()], message)
+    }
+
+    pub fn value(&mut self, name: &str, value: &dyn Display) -> core::fmt::Result {
+        self.record("value", &[&name, value], None)
+    }
+
+    pub fn panic(&mut self, message: &dyn Display) -> core::fmt::Result {
+        self.record("panic", &[message], None)
+    }
+
+    pub fn fault(&mut self, count: u32, message: &dyn Display) -> core::fmt::Result {
+        self.record("fault", &[&count, message], None)
+    }
+
+    pub fn end(&mut self) -> core::fmt::Result {
+        self.record("end", &[], None)
+    }
+}
+
+/// How much output is buffered for the sink.
+pub const BUFFER_LEN: usize = 512;
+
+/// Records waiting to be written out to the result sink.
+///
+/// A record that does not fit is dropped whole, as is anything after it until the buffer has been
+/// written out, so that only whole lines get out.
+pub struct RecordBuffer {
+    buf: [u8; BUFFER_LEN],
+    len: usize,
+    /// The end of the last whole record.
+    complete: usize,
+    overflowed: bool,
+}
+impl RecordBuffer {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; BUFFER_LEN],
+            len: 0,
+            complete: 0,
+            overflowed: false,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.complete == 0
+    }
+
+    /// The whole records in the buffer.
+    pub fn as_str(&self) -> &str {
+        // Only whole strings are ever copied in
+        core::str::from_utf8(&self.buf[..self.complete]).unwrap()
+    }
+}
+impl Default for RecordBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Write for RecordBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.overflowed || s.len() > BUFFER_LEN - self.len {
+            self.len = self.complete;
+            self.overflowed = true;
+            return Err(core::fmt::Error);
+        }
+
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        // Fields are escaped, so only the end of a record has a line feed
+        if s.ends_with('\n') {
+            self.complete = self.len;
+        }
+        Ok(())
+    }
+}
+
+struct Sink {
+    /// Whether a sink has been set, as records are only buffered once it has.
+    set: bool,
+    /// The output, unless it is being written to.
+    output: Option<&'static mut dyn Write>,
+    pending: RecordBuffer,
+}
+
+struct SinkCell(RefCell<Sink>);
+// SAFETY: The sink is only ever accessed from within a critical section.
+unsafe impl Sync for SinkCell {}
+
+static SINK: SinkCell = SinkCell(RefCell::new(Sink {
+    set: false,
+    output: None,
+    pending: RecordBuffer::new(),
+}));
+
+/// Sets where results are emitted to, sending the `hello` record, followed by a `fault` record
+/// if the device panicked before the last reset.
+pub fn set_result_sink(output: &'static mut dyn Write) {
+    critical_section::with(|_| {
+        let mut sink = SINK.0.borrow_mut();
+        sink.set = true;
+        sink.pending = RecordBuffer::new();
+
+        let mut writer = ResultWriter::new(&mut sink.pending);
+        writer.hello().ok();
+        if let Some(record) = PanicRecord::previous() {
+            writer.fault(record.faults(), &record).ok();
+        }
+    });
+    write_pending(output);
+}
+
+/// Emits records to the result sink, if one has been set.
+///
+/// The records are only buffered within a critical section, and written out after it, so that
+/// a slow output does not hold up interrupts. Records emitted meanwhile, for example by an
+/// interrupt, are written out along with them.
+///
+/// Results are strictly best effort, so any output errors are ignored, as are records that do not
+/// fit in the buffer, or are emitted while it is in use, for example by a panic during formatting.
+pub fn emit_results(f: impl FnOnce(&mut ResultWriter<&mut RecordBuffer>) -> core::fmt::Result) {
+    let output = critical_section::with(|_| {
+        let mut sink = SINK.0.try_borrow_mut().ok()?;
+        if !sink.set {
+            return None;
+        }
+
+        f(&mut ResultWriter::new(&mut sink.pending)).ok();
+        // Whoever is writing to the output already will write these out too
+        sink.output.take()
+    });
+
+    if let Some(output) = output {
+        write_pending(output);
+    }
+}
+
+/// Writes the pending records to the output until there are none left, then hands the output
+/// back to the sink.
+fn write_pending(output: &'static mut dyn Write) {
+    let mut output = Some(output);
+    while let Some(records) = critical_section::with(|_| {
+        let mut sink = SINK.0.borrow_mut();
+        if sink.pending.is_empty() {
+            sink.output = output.take();
+            None
+        } else {
+            Some(core::mem::take(&mut sink.pending))
+        }
+    }) {
+        if let Some(output) = output.as_mut() {
+            output.write_str(records.as_str()).ok();
+        }
+    }
+}
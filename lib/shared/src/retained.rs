@@ -0,0 +1,67 @@
+//! Values that are kept in RAM across a reset, to tell a warm restart from a power on.
+//!
+//! Startup code zeroes `.bss` and copies `.data`, so a [`Retained`] has to be placed where it
+//! leaves memory alone, which `cortex-m-rt` provides with its `.uninit` section:
+//! ```ignore
+//! #[cfg_attr(
+//!     any(feature = "metro", feature = "pygamer"),
+//!     unsafe(link_section = ".uninit.NAME")
+//! )]
+//! static NAME: Retained<T> = Retained::new();
+//! ```
+//! On the host, where the section is left out, it simply starts empty.
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+
+/// Marks a value as stored, as whatever is in the RAM after a power on is unlikely to match it.
+const MAGIC: u32 = 0x5EA1_AB1E;
+
+/// A value stored for after the next reset.
+pub struct Retained<T> {
+    cell: UnsafeCell<(u32, MaybeUninit<T>)>,
+}
+
+// SAFETY: The cell is only accessed in critical sections
+unsafe impl<T: Send> Sync for Retained<T> {}
+
+impl<T: Copy> Retained<T> {
+    pub const fn new() -> Self {
+        Self {
+            cell: UnsafeCell::new((0, MaybeUninit::uninit())),
+        }
+    }
+
+    /// Stores the value, replacing any that was stored before.
+    pub fn store(&self, value: T) {
+        critical_section::with(|_| {
+            let cell = self.cell.get();
+            // SAFETY: The critical section keeps the access exclusive. The marker goes last, so
+            // that a reset part way through leaves nothing stored.
+            unsafe {
+                core::ptr::write_volatile(&raw mut (*cell).1, MaybeUninit::new(value));
+                core::ptr::write_volatile(&raw mut (*cell).0, MAGIC);
+            }
+        });
+    }
+
+    /// Takes the value stored before the reset, if any, so that the next reset finds none.
+    pub fn take(&self) -> Option<T> {
+        critical_section::with(|_| {
+            let cell = self.cell.get();
+            // SAFETY: The critical section keeps the access exclusive, and the value was written
+            // whenever the marker is there
+            unsafe {
+                if core::ptr::read_volatile(&raw const (*cell).0) != MAGIC {
+                    return None;
+                }
+                core::ptr::write_volatile(&raw mut (*cell).0, 0);
+                Some(core::ptr::read_volatile(&raw const (*cell).1).assume_init())
+            }
+        })
+    }
+}
+impl<T: Copy> Default for Retained<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,833 @@
+//! Hardware-independent view of the RTC used by the test flows.
+//!
+//! The flows in [`crate::tests`] only depend on these traits so that they can run against the
+//! real `atsamd-hal` RTC on the boards as well as against fakes on the host.
+use embedded_hal::delay::DelayNs;
+use fugit::SecsDurationU32;
+
+/// A calendar date and time in the format kept by the RTC clock mode.
+///
+/// This mirrors the `atsamd-hal` type so that the flows do not depend on the HAL.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Datetime {
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    pub day: u8,
+    pub month: u8,
+    /// Years since 2000.
+    pub year: u8,
+}
+impl Datetime {
+    /// The year field is six bits wide.
+    pub const MAX_YEAR: u8 = 63;
+
+    /// The number of days in the month, by the rules of the hardware, which treats every year
+    /// divisible by four as a leap year.
+    pub const fn days_in_month(&self) -> u8 {
+        match self.month {
+            2 if self.year.is_multiple_of(4) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Whether every field is in range, and the day exists in the month.
+    pub const fn is_valid(&self) -> bool {
+        self.seconds < 60
+            && self.minutes < 60
+            && self.hours < 24
+            && self.year <= Self::MAX_YEAR
+            && matches!(self.month, 1..=12)
+            && self.day >= 1
+            && self.day <= self.days_in_month()
+    }
+}
+
+/// The date or time given to [`RtcClock::try_set_time`] does not exist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidDatetime;
+
+/// The register given to [`RtcBackup::set_backup`] does not exist.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NoBackupRegister;
+
+/// An RTC running in 32-bit counter mode.
+pub trait RtcCount32: DelayNs {
+    type ClockMode: RtcClock;
+    type Count16Mode: RtcCount16<Count32Mode = Self>;
+
+    fn count32(&self) -> u32;
+    fn set_count32(&mut self, count: u32);
+    fn reset_and_compute_prescaler(&mut self, timeout: SecsDurationU32);
+
+    /// Starts a periodic countdown, like the ehal 0.2 `CountDown::start`.
+    fn start_countdown(&mut self, timeout: SecsDurationU32);
+    /// Checks whether the countdown period has elapsed, like the ehal 0.2 `CountDown::wait`.
+    fn wait_countdown(&mut self) -> nb::Result<(), core::convert::Infallible>;
+
+    fn into_clock_mode(self) -> Self::ClockMode;
+
+    /// Switches to 16-bit counter mode, without a prescaler and with the longest period.
+    fn into_count16_mode(self) -> Self::Count16Mode;
+}
+
+/// An RTC running in 16-bit counter mode, which counts up to its period and then overflows to 0.
+///
+/// Its flags are polled rather than taken from the interrupt.
+pub trait RtcCount16 {
+    type Count32Mode: RtcCount32;
+
+    fn count16(&self) -> u16;
+    fn set_count16(&mut self, count: u16);
+    fn set_period(&mut self, period: u16);
+    /// Sets compare 0 or 1.
+    fn set_compare16(&mut self, n: usize, count: u16);
+
+    /// Takes the compare and overflow flags as [`RtcFlag`] masks, clearing them.
+    fn take_flags(&mut self) -> u8;
+
+    /// Switches back to 32-bit counter mode as the HAL sets it up, without a prescaler.
+    fn into_count32_mode(self) -> Self::Count32Mode;
+}
+
+/// An RTC interrupt flag, in a layout shared by both chips.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RtcFlag {
+    /// Compare 0 in counter mode, or alarm 0 in clock mode.
+    Match,
+    /// Compare 1 in counter mode.
+    Match1,
+    Overflow,
+    /// Any of the periodic intervals.
+    Periodic,
+}
+impl RtcFlag {
+    pub const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// What the RTC interrupt handler has seen since it was last asked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RtcInterrupt {
+    /// Every flag that was set, as [`RtcFlag`] masks. The handler clears them.
+    pub flags: u8,
+    /// How many times the handler ran.
+    pub times: u32,
+}
+
+/// The RTC interrupt, as recorded by its handler.
+pub trait RtcInterrupts {
+    /// Takes what the handler has seen, if it has run since the last call.
+    fn take_interrupts(&mut self) -> Option<RtcInterrupt>;
+}
+
+/// Compare 0 and the periodic interrupts of an RTC in 32-bit counter mode.
+pub trait RtcCompare: RtcCount32 + RtcInterrupts {
+    /// Whether the hardware has periodic interrupts, which the SAMD21 only has as events.
+    const HAS_PERIODIC: bool;
+
+    /// Interrupts once the count reaches `count`.
+    fn set_compare(&mut self, count: u32);
+    fn disable_compare(&mut self);
+
+    /// Interrupts every `2^(n + 3)` cycles of the RTC clock, regardless of the prescaler. This
+    /// does nothing without [`HAS_PERIODIC`](Self::HAS_PERIODIC).
+    fn enable_periodic(&mut self, n: u8);
+    fn disable_periodic(&mut self);
+}
+
+/// Which fields of the clock alarm 0 compares, each including those before it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlarmMask {
+    Seconds = 1,
+    Minutes = 2,
+    Hours = 3,
+    Day = 4,
+}
+
+/// Alarm 0 of an RTC in calendar clock mode.
+pub trait RtcAlarm: RtcClock + RtcInterrupts {
+    /// Interrupts whenever the fields of the clock picked by `mask` match those of `time`.
+    fn set_alarm(&mut self, time: Datetime, mask: AlarmMask);
+    fn disable_alarm(&mut self);
+}
+
+/// What an RTC in calendar clock mode keeps across a reset besides the time.
+///
+/// The SAMD51 RTC is in the backup domain, with general purpose registers and a timestamp that
+/// tamper events capture the time into. The SAMD21 RTC has neither.
+pub trait RtcBackup: RtcClock {
+    /// How many general purpose registers there are.
+    const BACKUP_REGISTERS: usize;
+    /// Whether tamper events capture a timestamp.
+    const HAS_TAMPER: bool;
+
+    /// The general purpose register `n`, unless there is no such register.
+    fn backup(&self, n: usize) -> Option<u32>;
+    fn set_backup(&mut self, n: usize, value: u32) -> Result<(), NoBackupRegister>;
+
+    /// Captures the time into the timestamp, with a tamper event from software.
+    fn trigger_tamper(&mut self);
+    /// Takes the time captured by the last tamper event, if there has been one since.
+    fn take_tamper_timestamp(&mut self) -> Option<Datetime>;
+}
+
+/// An RTC running in calendar clock mode.
+pub trait RtcClock {
+    fn current_time(&self) -> Datetime;
+
+    /// Sets the time as is. The hardware does not check it, and counts on from an invalid time in
+    /// ways of its own.
+    fn set_time(&mut self, time: Datetime);
+
+    /// Sets the time, unless it is not a valid date and time, which is checked here as the
+    /// hardware does not.
+    fn try_set_time(&mut self, time: Datetime) -> Result<(), InvalidDatetime> {
+        if !time.is_valid() {
+            return Err(InvalidDatetime);
+        }
+
+        self.set_time(time);
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+mod hal_impls {
+    use super::{
+        Datetime, RtcClock, RtcCount16, RtcCount32,
+        hal_regs::{disable, enable, flags, regs, rtc_flags, sync},
+    };
+    use atsamd_hal::{
+        prelude::_embedded_hal_timer_CountDown as Countdown,
+        rtc::{self, ClockMode, Count32Mode, Rtc},
+    };
+    use fugit::SecsDurationU32;
+
+    impl From<rtc::Datetime> for Datetime {
+        fn from(value: rtc::Datetime) -> Self {
+            Self {
+                seconds: value.seconds,
+                minutes: value.minutes,
+                hours: value.hours,
+                day: value.day,
+                month: value.month,
+                year: value.year,
+            }
+        }
+    }
+
+    impl From<Datetime> for rtc::Datetime {
+        fn from(value: Datetime) -> Self {
+            Self {
+                seconds: value.seconds,
+                minutes: value.minutes,
+                hours: value.hours,
+                day: value.day,
+                month: value.month,
+                year: value.year,
+            }
+        }
+    }
+
+    impl RtcCount32 for Rtc<Count32Mode> {
+        type ClockMode = Rtc<ClockMode>;
+        type Count16Mode = Count16Rtc;
+
+        #[inline]
+        fn count32(&self) -> u32 {
+            Rtc::count32(self)
+        }
+
+        #[inline]
+        fn set_count32(&mut self, count: u32) {
+            Rtc::set_count32(self, count);
+        }
+
+        #[inline]
+        fn reset_and_compute_prescaler(&mut self, timeout: SecsDurationU32) {
+            Rtc::reset_and_compute_prescaler(self, timeout);
+        }
+
+        #[inline]
+        fn start_countdown(&mut self, timeout: SecsDurationU32) {
+            Countdown::start(self, timeout);
+        }
+
+        #[inline]
+        fn wait_countdown(&mut self) -> nb::Result<(), core::convert::Infallible> {
+            // The HAL error type is uninhabited, so this can only ever be `WouldBlock`
+            Countdown::wait(self).map_err(|_| nb::Error::WouldBlock)
+        }
+
+        #[inline]
+        fn into_clock_mode(self) -> Self::ClockMode {
+            Rtc::into_clock_mode(self)
+        }
+
+        fn into_count16_mode(self) -> Self::Count16Mode {
+            let rtc = regs();
+            disable(&rtc);
+            #[cfg(feature = "metro")]
+            rtc.mode1()
+                .ctrl()
+                .write(|w| w.mode().count16().prescaler().div1());
+            #[cfg(feature = "pygamer")]
+            rtc.mode1()
+                .ctrla()
+                .write(|w| w.mode().count16().prescaler().div1().countsync().set_bit());
+            sync(&rtc);
+            rtc.mode1().per().write(|w| unsafe { w.bits(u16::MAX) });
+            sync(&rtc);
+            enable(&rtc);
+
+            Count16Rtc(self)
+        }
+    }
+
+    /// The RTC in 16-bit counter mode, which the HAL has no driver for.
+    ///
+    /// This holds on to the 32-bit counter driver, which is handed back once the mode is
+    /// switched back.
+    pub struct Count16Rtc(Rtc<Count32Mode>);
+
+    impl RtcCount16 for Count16Rtc {
+        type Count32Mode = Rtc<Count32Mode>;
+
+        fn count16(&self) -> u16 {
+            let rtc = regs();
+            #[cfg(feature = "metro")]
+            {
+                rtc.mode1().readreq().write(|w| w.rreq().set_bit());
+                sync(&rtc);
+            }
+            #[cfg(feature = "pygamer")]
+            while rtc.mode1().syncbusy().read().count().bit_is_set() {}
+            rtc.mode1().count().read().bits()
+        }
+
+        fn set_count16(&mut self, count: u16) {
+            let rtc = regs();
+            rtc.mode1().count().write(|w| unsafe { w.bits(count) });
+            sync(&rtc);
+        }
+
+        fn set_period(&mut self, period: u16) {
+            let rtc = regs();
+            rtc.mode1().per().write(|w| unsafe { w.bits(period) });
+            sync(&rtc);
+        }
+
+        fn set_compare16(&mut self, n: usize, count: u16) {
+            let rtc = regs();
+            rtc.mode1().comp(n).write(|w| unsafe { w.bits(count) });
+            sync(&rtc);
+        }
+
+        fn take_flags(&mut self) -> u8 {
+            let rtc = regs();
+            let set = rtc.mode1().intflag().read().bits()
+                & (flags::MATCH | flags::MATCH1 | flags::OVERFLOW);
+            rtc.mode1().intflag().write(|w| unsafe { w.bits(set) });
+            rtc_flags(set)
+        }
+
+        fn into_count32_mode(self) -> Self::Count32Mode {
+            let rtc = regs();
+            disable(&rtc);
+            #[cfg(feature = "metro")]
+            rtc.mode0()
+                .ctrl()
+                .write(|w| w.mode().count32().prescaler().div1());
+            #[cfg(feature = "pygamer")]
+            rtc.mode0()
+                .ctrla()
+                .write(|w| w.mode().count32().prescaler().div1().countsync().set_bit());
+            sync(&rtc);
+            enable(&rtc);
+
+            self.0
+        }
+    }
+
+    impl RtcClock for Rtc<ClockMode> {
+        #[inline]
+        fn current_time(&self) -> Datetime {
+            Rtc::current_time(self).into()
+        }
+
+        #[inline]
+        fn set_time(&mut self, time: Datetime) {
+            Rtc::set_time(self, time.into());
+        }
+    }
+}
+
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+pub use hal_impls::Count16Rtc;
+
+/// The backup registers and tamper timestamp, and the clock as a reset left it.
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+mod hal_backup {
+    #[cfg(feature = "pygamer")]
+    use super::hal_regs::{disable, enable, regs};
+    use super::{
+        Datetime, NoBackupRegister, RtcBackup, RtcClock,
+        hal_regs::{clock_bits, datetime, sync},
+    };
+    use atsamd_hal::{
+        pac,
+        rtc::{ClockMode, Rtc},
+    };
+
+    /// The RTC in calendar clock mode, as the last boot left it.
+    ///
+    /// The HAL driver resets the RTC when it is created, which would lose the time kept across
+    /// the reset, so this reads and sets the clock register itself.
+    pub struct RetainedClock(pac::Rtc);
+    impl RetainedClock {
+        /// Takes over the RTC without changing any of its settings. The RTC clock has to be set
+        /// up already.
+        pub fn new(rtc: pac::Rtc) -> Self {
+            Self(rtc)
+        }
+
+        pub fn free(self) -> pac::Rtc {
+            self.0
+        }
+    }
+
+    impl RtcClock for RetainedClock {
+        fn current_time(&self) -> Datetime {
+            #[cfg(feature = "metro")]
+            {
+                self.0.mode2().readreq().write(|w| w.rreq().set_bit());
+                sync(&self.0);
+            }
+            #[cfg(feature = "pygamer")]
+            while self.0.mode2().syncbusy().read().clock().bit_is_set() {}
+            datetime(self.0.mode2().clock().read().bits())
+        }
+
+        fn set_time(&mut self, time: Datetime) {
+            self.0
+                .mode2()
+                .clock()
+                .write(|w| unsafe { w.bits(clock_bits(time)) });
+            sync(&self.0);
+        }
+    }
+
+    /// The event system user that is the tamper input of the RTC.
+    #[cfg(feature = "pygamer")]
+    const RTC_TAMPER_USER: usize = 0;
+    /// The event channel that software tamper events are sent on.
+    #[cfg(feature = "pygamer")]
+    const TAMPER_CHANNEL: usize = 0;
+
+    #[cfg(feature = "metro")]
+    macro_rules! impl_backup {
+        ($rtc:ty) => {
+            impl RtcBackup for $rtc {
+                const BACKUP_REGISTERS: usize = 0;
+                const HAS_TAMPER: bool = false;
+
+                fn backup(&self, _n: usize) -> Option<u32> {
+                    None
+                }
+
+                fn set_backup(&mut self, _n: usize, _value: u32) -> Result<(), NoBackupRegister> {
+                    Err(NoBackupRegister)
+                }
+
+                fn trigger_tamper(&mut self) {}
+
+                fn take_tamper_timestamp(&mut self) -> Option<Datetime> {
+                    None
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "pygamer")]
+    macro_rules! impl_backup {
+        ($rtc:ty) => {
+            impl RtcBackup for $rtc {
+                const BACKUP_REGISTERS: usize = 4;
+                const HAS_TAMPER: bool = true;
+
+                fn backup(&self, n: usize) -> Option<u32> {
+                    (n < Self::BACKUP_REGISTERS).then(|| regs().mode2().gp(n).read().bits())
+                }
+
+                fn set_backup(&mut self, n: usize, value: u32) -> Result<(), NoBackupRegister> {
+                    if n >= Self::BACKUP_REGISTERS {
+                        return Err(NoBackupRegister);
+                    }
+
+                    let rtc = regs();
+                    rtc.mode2().gp(n).write(|w| unsafe { w.bits(value) });
+                    sync(&rtc);
+                    Ok(())
+                }
+
+                /// Sends a software event to the tamper input, which the RTC answers by capturing
+                /// the time.
+                fn trigger_tamper(&mut self) {
+                    let rtc = regs();
+                    // The event input can only be enabled while the RTC is disabled
+                    if rtc.mode2().evctrl().read().tampevei().bit_is_clear() {
+                        disable(&rtc);
+                        rtc.mode2().evctrl().modify(|_, w| w.tampevei().set_bit());
+                        enable(&rtc);
+                    }
+
+                    // SAFETY: Nothing else uses the event system
+                    let mclk = unsafe { pac::Mclk::steal() };
+                    let evsys = unsafe { pac::Evsys::steal() };
+                    mclk.apbbmask().modify(|_, w| w.evsys_().set_bit());
+                    evsys
+                        .channel(TAMPER_CHANNEL)
+                        .channel()
+                        .write(|w| w.path().asynchronous());
+                    evsys
+                        .user(RTC_TAMPER_USER)
+                        .write(|w| unsafe { w.channel().bits(TAMPER_CHANNEL as u8 + 1) });
+                    evsys.swevt().write(|w| w.channel0().set_bit());
+                }
+
+                fn take_tamper_timestamp(&mut self) -> Option<Datetime> {
+                    let rtc = regs();
+                    if rtc.mode2().intflag().read().tamper().bit_is_clear() {
+                        return None;
+                    }
+
+                    let time = datetime(rtc.mode2().timestamp().read().bits());
+                    rtc.mode2().tampid().write(|w| w.tampevt().set_bit());
+                    rtc.mode2().intflag().write(|w| w.tamper().set_bit());
+                    Some(time)
+                }
+            }
+        };
+    }
+
+    impl_backup!(Rtc<ClockMode>);
+    impl_backup!(RetainedClock);
+}
+
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+pub use hal_backup::RetainedClock;
+
+/// The RTC as a bare 32-bit counter, for timing with before the RTIC monotonic takes it over.
+#[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
+mod hal_counter {
+    use super::hal_regs::sync;
+    use atsamd_hal::pac;
+
+    /// The RTC counting at the rate of its clock, which has to be set up already.
+    pub struct RawCounter(pac::Rtc);
+    impl RawCounter {
+        /// Resets the RTC and starts it counting from zero, keeping the count synchronized so
+        /// that reading it does not have to wait.
+        pub fn new(rtc: pac::Rtc) -> Self {
+            reset(&rtc);
+            let mode0 = rtc.mode0();
+            #[cfg(feature = "metro")]
+            {
+                mode0
+                    .readreq()
+                    .write(|w| w.rcont().set_bit().rreq().set_bit());
+                mode0
+                    .ctrl()
+                    .write(|w| w.mode().count32().enable().set_bit());
+            }
+            #[cfg(feature = "pygamer")]
+            mode0
+                .ctrla()
+                .write(|w| w.mode().count32().countsync().set_bit().enable().set_bit());
+            sync(&rtc);
+            Self(rtc)
+        }
+
+        pub fn count(&self) -> u32 {
+            self.0.mode0().count().read().bits()
+        }
+    }
+
+    fn reset(rtc: &pac::Rtc) {
+        #[cfg(feature = "metro")]
+        rtc.mode0().ctrl().write(|w| w.swrst().set_bit());
+        #[cfg(feature = "pygamer")]
+        rtc.mode0().ctrla().write(|w| w.swrst().set_bit());
+        sync(rtc);
+    }
+}
+
+#[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
+pub(crate) use hal_counter::RawCounter;
+
+/// Register access shared by the extensions below, for what the HAL driver does not cover.
+#[cfg(any(feature = "metro", feature = "pygamer"))]
+mod hal_regs {
+    use super::{Datetime, RtcFlag};
+    use atsamd_hal::pac;
+
+    /// Interrupt flags of the SAMD21, which are the same in every mode.
+    #[cfg(feature = "metro")]
+    pub mod flags {
+        pub type Bits = u8;
+        pub const MATCH: Bits = 1 << 0;
+        pub const MATCH1: Bits = 1 << 1;
+        pub const OVERFLOW: Bits = 1 << 7;
+        pub const PERIODIC: Bits = 0;
+    }
+    /// Interrupt flags of the SAMD51, which are the same in every mode.
+    #[cfg(feature = "pygamer")]
+    pub mod flags {
+        pub type Bits = u16;
+        pub const MATCH: Bits = 1 << 8;
+        pub const MATCH1: Bits = 1 << 9;
+        pub const OVERFLOW: Bits = 1 << 15;
+        pub const PERIODIC: Bits = 0xFF;
+    }
+
+    /// The RTC registers, which the driver only uses to count and keep time.
+    pub fn regs() -> pac::Rtc {
+        // SAFETY: The driver does not touch the interrupt, compare and alarm registers, and is
+        // parked while the extensions change the mode
+        unsafe { pac::Rtc::steal() }
+    }
+
+    pub fn sync(rtc: &pac::Rtc) {
+        #[cfg(feature = "metro")]
+        while rtc.mode0().status().read().syncbusy().bit_is_set() {}
+        #[cfg(feature = "pygamer")]
+        while rtc.mode0().syncbusy().read().bits() != 0 {}
+    }
+
+    pub fn disable(rtc: &pac::Rtc) {
+        #[cfg(feature = "metro")]
+        rtc.mode0().ctrl().modify(|_, w| w.enable().clear_bit());
+        #[cfg(feature = "pygamer")]
+        rtc.mode0().ctrla().modify(|_, w| w.enable().clear_bit());
+        sync(rtc);
+    }
+
+    pub fn enable(rtc: &pac::Rtc) {
+        #[cfg(feature = "metro")]
+        rtc.mode0().ctrl().modify(|_, w| w.enable().set_bit());
+        #[cfg(feature = "pygamer")]
+        rtc.mode0().ctrla().modify(|_, w| w.enable().set_bit());
+        sync(rtc);
+    }
+
+    /// The clock register layout of a time, in 24 hour mode.
+    pub fn clock_bits(time: Datetime) -> u32 {
+        u32::from(time.seconds)
+            | u32::from(time.minutes) << 6
+            | u32::from(time.hours) << 12
+            | u32::from(time.day) << 17
+            | u32::from(time.month) << 22
+            | u32::from(time.year) << 26
+    }
+
+    /// The time in a clock register, or in the tamper timestamp, which has the same layout.
+    pub fn datetime(bits: u32) -> Datetime {
+        let field = |shift: u32, width: u32| ((bits >> shift) & ((1 << width) - 1)) as u8;
+        Datetime {
+            seconds: field(0, 6),
+            minutes: field(6, 6),
+            hours: field(12, 5),
+            day: field(17, 5),
+            month: field(22, 4),
+            year: field(26, 6),
+        }
+    }
+
+    /// Converts flags of the chip to [`RtcFlag`] masks.
+    pub fn rtc_flags(bits: flags::Bits) -> u8 {
+        [
+            (flags::MATCH, RtcFlag::Match),
+            (flags::MATCH1, RtcFlag::Match1),
+            (flags::OVERFLOW, RtcFlag::Overflow),
+            (flags::PERIODIC, RtcFlag::Periodic),
+        ]
+        .into_iter()
+        .filter(|&(mask, _)| bits & mask != 0)
+        .fold(0, |seen, (_, flag)| seen | flag.mask())
+    }
+}
+
+/// The RTC interrupt handler, and the alarms and compares that it reports on.
+///
+/// RTIC programs leave the interrupt to the monotonic, so this is only for the others, and even
+/// then only for those that bind it with [`bind_rtc_interrupt!`](crate::bind_rtc_interrupt).
+#[cfg(all(
+    any(feature = "metro", feature = "pygamer"),
+    not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+))]
+mod hal_interrupts {
+    use super::{
+        AlarmMask, Datetime, RtcAlarm, RtcCompare, RtcInterrupt, RtcInterrupts,
+        hal_regs::{clock_bits, flags, regs, rtc_flags, sync},
+    };
+    use atsamd_hal::{
+        pac::{self, NVIC},
+        rtc::{ClockMode, Count32Mode, Rtc},
+    };
+    use core::cell::{Cell, RefCell};
+    use core::future::poll_fn;
+    use core::task::{Poll, Waker};
+    use critical_section::Mutex;
+
+    static INTERRUPTS: Mutex<Cell<Option<RtcInterrupt>>> = Mutex::new(Cell::new(None));
+    /// Who is waiting in [`next_interrupt`].
+    static WAKER: Mutex<RefCell<Option<Waker>>> = Mutex::new(RefCell::new(None));
+
+    /// Clears the flags and enables the interrupts of `mask`.
+    fn enable(mask: flags::Bits) {
+        let rtc = regs();
+        rtc.mode0().intflag().write(|w| unsafe { w.bits(mask) });
+        rtc.mode0().intenset().write(|w| unsafe { w.bits(mask) });
+        // SAFETY: The handler below only touches the RTC and its own state, and a program that
+        // uses these interrupts binds it
+        unsafe { NVIC::unmask(pac::Interrupt::RTC) };
+    }
+
+    fn disable(mask: flags::Bits) {
+        regs().mode0().intenclr().write(|w| unsafe { w.bits(mask) });
+    }
+
+    fn take() -> Option<RtcInterrupt> {
+        critical_section::with(|cs| INTERRUPTS.borrow(cs).take())
+    }
+
+    /// Waits for the handler to run, with what it has seen since it was last asked, as
+    /// [`take_interrupts`](RtcInterrupts::take_interrupts) would return it.
+    ///
+    /// The check and the waker are taken care of in one critical section, so that an interrupt
+    /// cannot land in between and leave the future waiting.
+    pub fn next_interrupt() -> impl Future<Output = RtcInterrupt> {
+        poll_fn(|cx| {
+            critical_section::with(|cs| match INTERRUPTS.borrow(cs).take() {
+                Some(irq) => Poll::Ready(irq),
+                None => {
+                    WAKER.borrow_ref_mut(cs).replace(cx.waker().clone());
+                    Poll::Pending
+                }
+            })
+        })
+    }
+
+    impl RtcInterrupts for Rtc<Count32Mode> {
+        fn take_interrupts(&mut self) -> Option<RtcInterrupt> {
+            take()
+        }
+    }
+
+    impl RtcCompare for Rtc<Count32Mode> {
+        const HAS_PERIODIC: bool = flags::PERIODIC != 0;
+
+        fn set_compare(&mut self, count: u32) {
+            let rtc = regs();
+            rtc.mode0().comp(0).write(|w| unsafe { w.bits(count) });
+            sync(&rtc);
+            enable(flags::MATCH);
+        }
+
+        fn disable_compare(&mut self) {
+            disable(flags::MATCH);
+        }
+
+        fn enable_periodic(&mut self, n: u8) {
+            enable(flags::PERIODIC & 1 << n);
+        }
+
+        fn disable_periodic(&mut self) {
+            disable(flags::PERIODIC);
+        }
+    }
+
+    impl RtcInterrupts for Rtc<ClockMode> {
+        fn take_interrupts(&mut self) -> Option<RtcInterrupt> {
+            take()
+        }
+    }
+
+    impl RtcAlarm for Rtc<ClockMode> {
+        fn set_alarm(&mut self, time: Datetime, mask: AlarmMask) {
+            let rtc = regs();
+            #[cfg(feature = "metro")]
+            {
+                rtc.mode2()
+                    .alarm(0)
+                    .write(|w| unsafe { w.bits(clock_bits(time)) });
+                sync(&rtc);
+                rtc.mode2().mask(0).write(|w| unsafe { w.bits(mask as u8) });
+            }
+            #[cfg(feature = "pygamer")]
+            {
+                rtc.mode2()
+                    .alarm0()
+                    .write(|w| unsafe { w.bits(clock_bits(time)) });
+                sync(&rtc);
+                rtc.mode2().mask0().write(|w| unsafe { w.bits(mask as u8) });
+            }
+            sync(&rtc);
+            enable(flags::MATCH);
+        }
+
+        fn disable_alarm(&mut self) {
+            disable(flags::MATCH);
+        }
+    }
+
+    /// Clears the flags that are set, records them for
+    /// [`take_interrupts`](RtcInterrupts::take_interrupts) and wakes [`next_interrupt`]. This is
+    /// the RTC interrupt handler, bound by [`bind_rtc_interrupt!`](crate::bind_rtc_interrupt).
+    pub fn on_rtc_interrupt() {
+        let rtc = regs();
+        let set = rtc.mode0().intflag().read().bits() & rtc.mode0().intenset().read().bits();
+        rtc.mode0().intflag().write(|w| unsafe { w.bits(set) });
+
+        critical_section::with(|cs| {
+            let cell = INTERRUPTS.borrow(cs);
+            let mut seen = cell.get().unwrap_or_default();
+            seen.flags |= rtc_flags(set);
+            seen.times += 1;
+            cell.set(Some(seen));
+
+            if let Some(waker) = WAKER.borrow_ref_mut(cs).take() {
+                waker.wake();
+            }
+        });
+    }
+}
+#[cfg(all(
+    any(feature = "metro", feature = "pygamer"),
+    not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+))]
+pub use hal_interrupts::{next_interrupt, on_rtc_interrupt};
+
+/// Binds the RTC interrupt to the handler that reports the compares and alarms of the RTC tests,
+/// for programs that run them.
+#[cfg(all(
+    any(feature = "metro", feature = "pygamer"),
+    not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+))]
+#[macro_export]
+macro_rules! bind_rtc_interrupt {
+    () => {
+        const _: () = {
+            use $crate::prelude::pac::interrupt;
+
+            #[interrupt]
+            fn RTC() {
+                $crate::rtc::on_rtc_interrupt();
+            }
+        };
+    };
+}
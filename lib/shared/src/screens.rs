@@ -1,7 +1,10 @@
 use crate::{
     Input,
-    display::{Display, DisplayWriter},
+    checks::CheckLog,
+    display::{Display, DisplayTextStyle, DisplayWriter},
+    results::emit_results,
 };
+use core::fmt::Write;
 use derive_new::new;
 use embedded_graphics::{prelude::*, text};
 
@@ -9,6 +12,8 @@ use embedded_graphics::{prelude::*, text};
 pub struct ScreensGen<D: Display + 'static, I> {
     display: D,
     input: I,
+    #[new(default)]
+    check_log: CheckLog,
 }
 impl<D: Display, I: Input> ScreensGen<D, I>
 where
@@ -21,24 +26,51 @@ where
         DisplayWriter::new(&mut self.display, style)
     }
 
-    fn button_message(&mut self) {
-        text::Text::with_text_style(
-            "Press a button to continue...",
+    /// Draws a line of text at the bottom of the screen.
+    pub(crate) fn footer(&mut self, message: impl core::fmt::Display) {
+        let style = DisplayTextStyle::new(
             Point::new(0, (self.display.size().height - 1) as i32),
+            None,
             D::character_style(),
             text::TextStyleBuilder::new()
                 .baseline(text::Baseline::Bottom)
                 .build(),
-        )
-        .draw(&mut self.display)
-        .unwrap();
+        );
+        write!(DisplayWriter::new(&mut self.display, style), "{message}").unwrap();
     }
 
     pub fn wait_for_button(&mut self) -> DisplayWriter<'_, D> {
-        self.button_message();
+        self.footer("Press a button to continue...");
         self.display.flush();
 
         self.input.wait_for_button();
         self.new_screen()
     }
+
+    /// Starts a new test, emitting its result record and clearing the check log.
+    pub fn start_test(&mut self, name: &str) {
+        self.check_log = CheckLog::default();
+        emit_results(|r| r.test(name));
+    }
+
+    /// The checks made since the current test started.
+    pub fn check_log(&self) -> &CheckLog {
+        &self.check_log
+    }
+
+    pub(crate) fn display(&mut self) -> &mut D {
+        &mut self.display
+    }
+
+    pub(crate) fn input(&mut self) -> &mut I {
+        &mut self.input
+    }
+
+    pub(crate) fn check_log_mut(&mut self) -> &mut CheckLog {
+        &mut self.check_log
+    }
+
+    pub fn free(self) -> (D, I) {
+        (self.display, self.input)
+    }
 }
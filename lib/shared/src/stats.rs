@@ -0,0 +1,57 @@
+//! Summary statistics of timing measurements, kept as they come in rather than stored.
+
+/// The minimum, maximum, mean and standard deviation of a series of samples.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stats {
+    count: u32,
+    min: i32,
+    max: i32,
+    sum: i64,
+    sum_squares: i64,
+}
+impl Stats {
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            min: i32::MAX,
+            max: i32::MIN,
+            sum: 0,
+            sum_squares: 0,
+        }
+    }
+
+    pub fn add(&mut self, sample: i32) {
+        self.count += 1;
+        self.min = self.min.min(sample);
+        self.max = self.max.max(sample);
+        self.sum += i64::from(sample);
+        self.sum_squares += i64::from(sample) * i64::from(sample);
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn min(&self) -> Option<i32> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn max(&self) -> Option<i32> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// The mean, rounded towards zero.
+    pub fn mean(&self) -> Option<i32> {
+        (self.count > 0).then(|| (self.sum / i64::from(self.count)) as i32)
+    }
+
+    /// The population standard deviation, rounded down.
+    pub fn std_dev(&self) -> Option<u32> {
+        (self.count > 0).then(|| {
+            let count = i128::from(self.count);
+            // n² times the variance, which keeps it exact in integers
+            let scaled = count * i128::from(self.sum_squares) - i128::from(self.sum).pow(2);
+            (scaled.isqrt() / count) as u32
+        })
+    }
+}
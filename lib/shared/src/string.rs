@@ -0,0 +1,52 @@
+//! Text formatted without an allocator.
+use core::fmt::{self, Write};
+
+/// Formatted text of up to `N` bytes.
+///
+/// Text beyond the capacity is dropped a whole character at a time, which is remembered so that
+/// the cut can be marked where the text is shown.
+#[derive(Clone, Copy)]
+pub struct FixedString<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+impl<const N: usize> FixedString<N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are ever copied in
+        core::str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+
+    /// Whether any text was dropped for lack of space.
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+}
+impl<const N: usize> Default for FixedString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const N: usize> Write for FixedString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut count = s.len().min(N - self.len);
+        if count < s.len() {
+            self.truncated = true;
+            while !s.is_char_boundary(count) {
+                count -= 1;
+            }
+        }
+
+        self.buf[self.len..self.len + count].copy_from_slice(&s.as_bytes()[..count]);
+        self.len += count;
+        Ok(())
+    }
+}
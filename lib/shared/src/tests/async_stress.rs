@@ -1,13 +1,25 @@
 use crate::{
+    deadlines::Deadlines,
     display::{Display, DisplayTextStyle, DisplayWriter},
-    monotonic::Mono,
+    input::AsyncInput,
+    monotonic::{MONO_TICK_HZ, Mono},
 };
 use atsamd_hal::prelude::*;
+use core::cell::RefCell;
 use core::fmt::Write;
+use critical_section::Mutex as CsMutex;
 use embedded_graphics::prelude::*;
 use embedded_graphics::{primitives, text};
 use rtic::Mutex;
 
+/// The wakeups of all of the test tasks.
+static DEADLINES: CsMutex<RefCell<Deadlines>> = CsMutex::new(RefCell::new(Deadlines::new()));
+
+/// The time of the monotonic in microseconds, from its ticks so that it does not overflow.
+fn now_us() -> u64 {
+    u64::from(Mono::now().ticks()) * 1_000_000 / u64::from(MONO_TICK_HZ)
+}
+
 struct Patch {
     rectangle: primitives::Rectangle,
 }
@@ -49,22 +61,81 @@ where
 {
     let patch = display.lock(|disp| Patch::new::<PATCH_SIZE>(disp.size().width, position));
 
+    let period_us = u64::from(delay_ms) * 1000;
     for color in colors.iter().cycle() {
         display.lock(|disp| {
             patch.draw(disp, *color);
         });
 
+        let sleep =
+            critical_section::with(|cs| DEADLINES.borrow_ref_mut(cs).sleep(now_us(), period_us));
         Mono::delay_ms(delay_ms).await;
+        critical_section::with(|cs| DEADLINES.borrow_ref_mut(cs).wake(sleep, now_us()));
     }
 
     unreachable!();
 }
 
+/// Shows the ticks of the monotonic, and above them how late the wakeups of the test tasks have
+/// been at worst and how many of them were missed, either by a period or more or altogether.
 pub async fn clock_task<D: Display, M: Mutex<T = D>>(mut display: M) -> ! {
     loop {
+        let (worst_lateness_us, missed) = critical_section::with(|cs| {
+            let deadlines = DEADLINES.borrow_ref(cs);
+            (
+                deadlines.worst_lateness_us(),
+                deadlines.misses() + deadlines.overdue(now_us()),
+            )
+        });
+        let verdict = if missed == 0 { "ok" } else { "FAIL" };
+
+        display.lock(|d| {
+            let style = |bottom| {
+                DisplayTextStyle::new(
+                    Point::new(0, bottom),
+                    None,
+                    D::character_style(),
+                    text::TextStyleBuilder::new()
+                        .baseline(text::Baseline::Bottom)
+                        .build(),
+                )
+            };
+            let line_height = D::FONT.character_size.height as i32;
+
+            write!(
+                DisplayWriter::new(d, style(64 - 2 * line_height)),
+                "{verdict} late {}ms missed {missed}  ",
+                worst_lateness_us / 1000
+            )
+            .unwrap();
+            write!(
+                DisplayWriter::new(d, style(64)),
+                "0x{:X}",
+                Mono::now().ticks()
+            )
+            .unwrap();
+        });
+
+        Mono::delay_ms(500).await;
+    }
+}
+
+/// Shows the last button pressed and how many presses there have been, with the bottom of the
+/// text at `position`.
+pub async fn button_task<D: Display, M: Mutex<T = D>, I: AsyncInput>(
+    mut display: M,
+    input: &mut I,
+    position: Point,
+) -> ! {
+    let mut presses: u32 = 0;
+
+    loop {
+        let button = input.press().await;
+        presses = presses.wrapping_add(1);
+
         display.lock(|d| {
             let style = DisplayTextStyle::new(
-                Point::new(0, 64),
+                position,
                 None,
                 D::character_style(),
                 text::TextStyleBuilder::new()
@@ -72,9 +143,11 @@ pub async fn clock_task<D: Display, M: Mutex<T = D>>(mut display: M) -> ! {
                     .build(),
             );
 
-            write!(DisplayWriter::new(d, style), "0x{:X}", Mono::now().ticks()).unwrap();
+            write!(
+                DisplayWriter::new(d, style),
+                "Pressed: {button:?} ({presses})   "
+            )
+            .unwrap();
         });
-
-        Mono::delay_ms(500).await;
     }
 }
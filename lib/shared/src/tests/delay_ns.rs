@@ -1,53 +1,284 @@
-use crate::{Input, display::Display, screens::ScreensGen};
-use atsamd_hal::{
-    delay::Delay,
-    ehal::delay::DelayNs,
-    rtc::{Count32Mode, Rtc},
-    timer::{Count16, TimerCounter},
+use crate::{
+    Input, check, check_range,
+    display::Display,
+    results::{Outcome, emit_results},
+    rtc::RtcCount32,
+    screens::ScreensGen,
+    string::FixedString,
 };
 use core::fmt::Write;
+use core::ops::RangeInclusive;
+use embedded_hal::delay::DelayNs;
 
-const DELAY_SECS: u32 = 3;
-const DELAY_MILLIS: u32 = DELAY_SECS * 1000;
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+
+/// A free-running counter that delays are timed against.
+pub trait Stopwatch {
+    fn rate_hz(&self) -> u32;
+
+    /// The current count, wrapping around.
+    fn ticks(&mut self) -> u32;
+
+    /// Whether this counts with the RTC, whose own delays then cannot be timed against it.
+    fn uses_rtc(&self) -> bool {
+        false
+    }
+
+    /// Whether this counts the core clock, which SysTick also counts.
+    fn uses_core_clock(&self) -> bool {
+        false
+    }
+}
+
+/// Which `DelayNs` method a step of the sweep calls.
+#[derive(Clone, Copy)]
+enum Unit {
+    Ns,
+    Us,
+    Ms,
+}
+
+/// A delay of the sweep.
+struct Step {
+    label: &'static str,
+    amount: u32,
+    unit: Unit,
+}
+impl Step {
+    const fn new(label: &'static str, amount: u32, unit: Unit) -> Self {
+        Self {
+            label,
+            amount,
+            unit,
+        }
+    }
+
+    fn nanos(&self) -> u64 {
+        let scale = match self.unit {
+            Unit::Ns => 1,
+            Unit::Us => 1_000,
+            Unit::Ms => 1_000_000,
+        };
+        u64::from(self.amount) * scale
+    }
+
+    /// How long the delay may take by a stopwatch that ticks every `tick_ns`: no shorter than
+    /// requested, and no longer than a tenth more plus `slack_ns` of fixed overhead, both give or
+    /// take a tick.
+    fn allowed(&self, tick_ns: u64, slack_ns: u64) -> RangeInclusive<u64> {
+        let requested = self.nanos();
+        requested.saturating_sub(tick_ns)
+            ..=requested + requested / OVERRUN_DIVISOR + slack_ns + tick_ns
+    }
+
+    fn run<E: DelayNs>(&self, delay: &mut E) {
+        match self.unit {
+            Unit::Ns => delay.delay_ns(self.amount),
+            Unit::Us => delay.delay_us(self.amount),
+            Unit::Ms => delay.delay_ms(self.amount),
+        }
+    }
+}
+
+/// The delays timed for every implementation, through each of the `DelayNs` methods.
+const SWEEP: [Step; 6] = [
+    Step::new("1us", 1_000, Unit::Ns),
+    Step::new("10us", 10_000, Unit::Ns),
+    Step::new("100us", 100, Unit::Us),
+    Step::new("1ms", 1_000, Unit::Us),
+    Step::new("10ms", 10, Unit::Ms),
+    Step::new("100ms", 100, Unit::Ms),
+];
+
+/// How far over the requested time a delay may run, as a fraction of it.
+const OVERRUN_DIVISOR: u64 = 10;
+/// Delays shorter than this many ticks of the stopwatch are too short to time, and are skipped.
+const MIN_TICKS: u64 = 10;
+
+const DELAY_SLACK_NS: u64 = 10_000;
+const TIMER_SLACK_NS: u64 = 20_000;
+/// A couple of ticks of the 1 kHz RTC clock, to which RTC delays are rounded up.
+const RTC_SLACK_NS: u64 = 2_000_000;
+
+/// How many RTC ticks a delay of less than one tick may take, as reading the count has to wait
+/// for it to be synchronized from the RTC clock.
+const SUB_TICK_MAX_TICKS: u32 = 3;
+/// How far before the wraparound of the RTC count a delay across it starts.
+const WRAP_MARGIN_MS: u32 = 10;
+/// How many RTC ticks a delay across the wraparound may run over.
+const WRAP_SLACK_TICKS: u32 = 3;
+
+/// Formats nanoseconds, moving to a larger unit before there are five digits.
+struct Nanos(u64);
+impl core::fmt::Display for Nanos {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            ns if ns < 10_000 => write!(f, "{ns}ns"),
+            ns if ns < 10_000_000 => write!(f, "{}us", ns / 1_000),
+            ns => write!(f, "{}ms", ns / 1_000_000),
+        }
+    }
+}
 
 impl<D: Display, I: Input> ScreensGen<D, I>
 where
     D::Error: core::fmt::Debug,
 {
-    fn test_delay<E: DelayNs>(&mut self, name: &str, delay: &mut E) {
-        let mut writer = self.new_screen();
+    /// Times every delay of the sweep against the stopwatch, showing the results on one screen
+    /// and then checking each against [`Step::allowed`].
+    fn test_delay<E: DelayNs, S: Stopwatch>(
+        &mut self,
+        name: &str,
+        delay: &mut E,
+        stopwatch: &mut S,
+        slack_ns: u64,
+    ) {
+        let tick_ns = NANOS_PER_SEC.div_ceil(u64::from(stopwatch.rate_hz()));
+        let mut results = [None; SWEEP.len()];
 
-        writeln!(writer, "Delaying {DELAY_SECS} for `{name}`...").unwrap();
+        let mut writer = self.new_screen();
+        writeln!(writer, "Timing `{name}`...").unwrap();
         writer.flush();
-        delay.delay_ms(DELAY_MILLIS);
-        writeln!(writer, "Waiting another {DELAY_SECS} seconds...").unwrap();
+
+        for (step, result) in SWEEP.iter().zip(&mut results) {
+            if step.nanos() < MIN_TICKS * tick_ns {
+                continue;
+            }
+
+            let start = stopwatch.ticks();
+            step.run(delay);
+            let ticks = stopwatch.ticks().wrapping_sub(start);
+            *result = Some(u64::from(ticks) * NANOS_PER_SEC / u64::from(stopwatch.rate_hz()));
+        }
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "`{name}`:").unwrap();
+        for (step, result) in SWEEP.iter().zip(results) {
+            let Some(measured) = result else {
+                writeln!(writer, "{:>5}: too short", step.label).unwrap();
+                continue;
+            };
+
+            let requested = step.nanos();
+            let passed = step.allowed(tick_ns, slack_ns).contains(&measured);
+            let error = measured as i64 - requested as i64;
+            let percent = error * 100 / requested as i64;
+            let verdict = if passed { "ok" } else { "FAIL" };
+            writeln!(
+                writer,
+                "{:>5}: {} {percent:+}% {verdict}",
+                step.label,
+                Nanos(measured)
+            )
+            .unwrap();
+        }
         writer.flush();
-        delay.delay_ms(DELAY_MILLIS);
-        writeln!(writer, "One more delay of {DELAY_SECS} seconds...").unwrap();
+
+        for (step, result) in SWEEP.iter().zip(results) {
+            let mut case = FixedString::<32>::new();
+            write!(case, "{name}/{}", step.label).unwrap();
+
+            match result {
+                Some(measured) => {
+                    let allowed = step.allowed(tick_ns, slack_ns);
+                    check_range!(self, name = case.as_str(), measured, allowed);
+                    emit_results(|r| r.value("measured_ns", &measured));
+                }
+                None => emit_results(|r| {
+                    r.case(
+                        case.as_str(),
+                        Outcome::Skip,
+                        Some(&"shorter than the stopwatch can time"),
+                    )
+                }),
+            }
+        }
+
+        self.wait_for_button();
+    }
+
+    /// Checks RTC delays that the sweep does not cover against the RTC count itself: delays of
+    /// less than one tick, and a delay across the wraparound of the count.
+    fn test_rtc_delay_edges<R: RtcCount32>(&mut self, rtc: &mut R, rtc_hz: u32) {
+        let tick_ns = (NANOS_PER_SEC / u64::from(rtc_hz)) as u32;
+
+        let sub_tick = [1, tick_ns / 2, tick_ns - 1].map(|ns| {
+            let start = rtc.count32();
+            rtc.delay_ns(ns);
+            (ns, rtc.count32().wrapping_sub(start))
+        });
+
+        // Start just short of the wraparound, and delay until as far past it
+        let margin_ticks = WRAP_MARGIN_MS * rtc_hz / 1000;
+        rtc.set_count32(u32::MAX - margin_ticks);
+        let start = rtc.count32();
+        rtc.delay_ms(2 * WRAP_MARGIN_MS);
+        let end = rtc.count32();
+        let ticks = end.wrapping_sub(start);
+        let expected = 2 * margin_ticks;
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "RTC delays at {rtc_hz} Hz:").unwrap();
+        for (ns, ticks) in sub_tick {
+            writeln!(writer, "{}: {ticks} ticks", Nanos(ns.into())).unwrap();
+        }
+        writeln!(writer, "Across wraparound:").unwrap();
+        writeln!(writer, "{start:#010X} to {end:#010X}").unwrap();
+        writeln!(writer, "{ticks} ticks for {expected}").unwrap();
         writer.flush();
-        delay.delay_ms(DELAY_MILLIS);
-        writeln!(writer, "\n`DelayNs` test for `{name}` complete!").unwrap();
+
+        for (_, ticks) in sub_tick {
+            check_range!(self, ticks, 0..=SUB_TICK_MAX_TICKS);
+        }
+        check!(self, end < start);
+        check_range!(self, ticks, expected..=expected + WRAP_SLACK_TICKS);
+        rtc.set_count32(0);
+
         self.wait_for_button();
     }
 
-    pub fn delay_ns_test<TC: Count16>(
-        mut self,
-        mut delay: Delay,
-        mut timer: TimerCounter<TC>,
-        mut _rtc: Rtc<Count32Mode>,
-    ) -> !
-    where
-        D::Error: core::fmt::Debug,
-    {
+    /// Times the SysTick delay, a timer/counter and the RTC against the stopwatch, then checks
+    /// the edge cases of RTC delays with the RTC clocked at `rtc_hz`.
+    ///
+    /// The RTC is not timed if the stopwatch counts with it.
+    pub fn run_delay_ns_test<E: DelayNs, T: DelayNs, R: RtcCount32, S: Stopwatch>(
+        &mut self,
+        delay: &mut E,
+        timer: &mut T,
+        rtc: &mut R,
+        rtc_hz: u32,
+        stopwatch: &mut S,
+    ) {
         let mut writer = self.new_screen();
         writeln!(writer, "Press button to start the tests").unwrap();
         self.wait_for_button();
 
-        self.test_delay("Delay", &mut delay);
-        self.test_delay("TimerCounter", &mut timer);
-        // TODO: This depends on the RTC rework PR being merged: https://github.com/atsamd-rs/atsamd/pull/845
-        //test_delay(&mut screens, "Rtc", &mut rtc);
+        self.test_delay("Delay", delay, stopwatch, DELAY_SLACK_NS);
+        self.test_delay("TimerCounter", timer, stopwatch, TIMER_SLACK_NS);
+        if stopwatch.uses_rtc() {
+            emit_results(|r| {
+                r.case(
+                    "Rtc",
+                    Outcome::Skip,
+                    Some(&"the stopwatch is the RTC, which cannot time its own delays"),
+                )
+            });
+        } else {
+            self.test_delay("Rtc", rtc, stopwatch, RTC_SLACK_NS);
+        }
+        self.test_rtc_delay_edges(rtc, rtc_hz);
+    }
 
+    pub fn delay_ns_test<E: DelayNs, T: DelayNs, R: RtcCount32, S: Stopwatch>(
+        mut self,
+        mut delay: E,
+        mut timer: T,
+        mut rtc: R,
+        rtc_hz: u32,
+        mut stopwatch: S,
+    ) -> ! {
+        self.start_test("delay_ns");
+        self.run_delay_ns_test(&mut delay, &mut timer, &mut rtc, rtc_hz, &mut stopwatch);
         self.test_complete();
     }
 }
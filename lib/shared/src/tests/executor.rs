@@ -0,0 +1,74 @@
+use crate::{
+    Input, check, check_eq, check_range,
+    display::Display,
+    executor::{Either, TimeSource, block_on_with_stats, sleep, with_timeout},
+    join,
+    screens::ScreensGen,
+    select,
+};
+use core::fmt::Write;
+
+const SHORT_MS: u32 = 50;
+const LONG_MS: u32 = 150;
+/// A sleep may end on the tick after its deadline.
+const SLACK_MS: u32 = 1;
+
+/// Blocks on the future, returning its output and how long it took, and adding up missed wakeups.
+fn timed<T: TimeSource, F: Future>(time: &T, missed: &mut u32, future: F) -> (F::Output, u32) {
+    let start = time.now_ms();
+    let (output, stats) = block_on_with_stats(future);
+    *missed += stats.missed;
+    (output, time.now_ms().wrapping_sub(start))
+}
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Runs sleeps together with `join!`, `select!` and `with_timeout`, checking how long each
+    /// takes and that the time source woke the executor for every one of them.
+    pub fn run_executor_test<T: TimeSource>(&mut self, time: &T) {
+        let mut writer = self.new_screen();
+        writeln!(writer, "Running futures together...").unwrap();
+        writer.flush();
+
+        let mut missed = 0;
+        let (_, joined_ms) = timed(
+            time,
+            &mut missed,
+            join!(sleep(time, SHORT_MS), sleep(time, LONG_MS)),
+        );
+        let (selected, selected_ms) = timed(
+            time,
+            &mut missed,
+            select!(sleep(time, LONG_MS), sleep(time, SHORT_MS)),
+        );
+        let (late, late_ms) = timed(
+            time,
+            &mut missed,
+            with_timeout(time, SHORT_MS, sleep(time, LONG_MS)),
+        );
+        let (in_time, in_time_ms) = timed(
+            time,
+            &mut missed,
+            with_timeout(time, LONG_MS, sleep(time, SHORT_MS)),
+        );
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "join: {joined_ms} ms").unwrap();
+        writeln!(writer, "select: {selected_ms} ms").unwrap();
+        writeln!(writer, "timed out: {late_ms} ms").unwrap();
+        writeln!(writer, "in time: {in_time_ms} ms").unwrap();
+        writer.flush();
+
+        check_range!(self, joined_ms, LONG_MS..=LONG_MS + SLACK_MS);
+        check!(self, selected == Either::Second(()));
+        check_range!(self, selected_ms, SHORT_MS..=SHORT_MS + SLACK_MS);
+        check!(self, late.is_err());
+        check_range!(self, late_ms, SHORT_MS..=SHORT_MS + SLACK_MS);
+        check!(self, in_time.is_ok());
+        check_range!(self, in_time_ms, SHORT_MS..=SHORT_MS + SLACK_MS);
+        check_eq!(self, missed, 0);
+        self.wait_for_button();
+    }
+}
@@ -1,20 +1,60 @@
-use crate::{Input, display::Display, screens::ScreensGen};
+use crate::{
+    Input,
+    checks::MAX_FAILURES,
+    display::{Display, DisplayWriter},
+    screens::ScreensGen,
+};
 use core::fmt::Write;
 
 #[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
 pub mod async_stress;
 mod delay_ns;
+mod executor;
+#[cfg(any(feature = "rtic-metro", feature = "rtic-pygamer"))]
+mod mono_stats;
 mod rtc;
+mod wakers;
+
+pub use delay_ns::Stopwatch;
+pub use rtc::RtcReset;
+pub use wakers::AsyncTimer;
 
 impl<D: Display, I: Input> ScreensGen<D, I>
 where
     D::Error: core::fmt::Debug,
 {
-    pub fn test_complete(mut self) -> ! {
+    /// Ends the current test, starting a screen that shows the tally of any checks.
+    pub(crate) fn end_test(&mut self) -> DisplayWriter<'_, D> {
+        crate::results::emit_results(|r| r.end());
+        let log = self.check_log().clone();
+        let (passed, failed) = (log.passed(), log.failed());
         let mut writer = self.new_screen();
 
-        writeln!(writer, "The test is complete.\n\nReset to run again.").unwrap();
+        writeln!(writer, "The test is complete.\n").unwrap();
+        if passed + failed > 0 {
+            let verdict = if failed == 0 { "PASS" } else { "FAIL" };
+            writeln!(writer, "{verdict}: {passed} checks passed, {failed} failed").unwrap();
+            for location in log.failures() {
+                writeln!(writer, "{}:{}", location.file_name(), location.line).unwrap();
+            }
+            if failed as usize > MAX_FAILURES {
+                writeln!(writer, "...").unwrap();
+            }
+            writeln!(writer).unwrap();
+        }
+
+        writer
+    }
+
+    /// Shows that the test is complete, along with the tally of any checks.
+    pub fn show_test_complete(&mut self) {
+        let mut writer = self.end_test();
+        writeln!(writer, "Reset to run again.").unwrap();
         writer.flush();
+    }
+
+    pub fn test_complete(mut self) -> ! {
+        self.show_test_complete();
         loop {
             cortex_m::asm::wfi();
         }
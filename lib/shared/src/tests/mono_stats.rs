@@ -0,0 +1,153 @@
+use super::Stopwatch;
+use crate::{
+    Input, check_range,
+    display::Display,
+    mono_backend::MonoBackend,
+    monotonic::{Mono, take_rtc_stopwatch},
+    results::{Outcome, emit_results},
+    screens::ScreensGen,
+    stats::Stats,
+};
+use core::fmt::Write;
+
+const MICROS_PER_SEC: u64 = 1_000_000;
+
+/// How many delays are timed.
+const DELAYS: u32 = 100;
+/// The delay that is asked for, which is a whole number of ticks of the SysTick monotonic but
+/// not of the RTC ones, so that their rounding shows.
+const DELAY_MS: u32 = 25;
+/// How much later than a tick of the monotonic past the delay a wakeup may be.
+const LATENCY_SLACK_US: i32 = 500;
+/// How far the monotonic may drift from the clock it is timed against, in parts per million, on
+/// top of the resolution of the timing.
+///
+/// The core clock of the Metro M0 is locked to the 32.768 kHz crystal that also clocks its RTC,
+/// so every pair of its clocks should agree to well within this.
+#[cfg(feature = "rtic-metro")]
+const MAX_DRIFT_PPM: i64 = 200;
+/// How far the monotonic may drift from the clock it is timed against, in parts per million, on
+/// top of the resolution of the timing.
+///
+/// The PyGamer has no crystal for its clocks, and the internal low power oscillator that clocks
+/// its RTC is only accurate to a few percent.
+#[cfg(feature = "rtic-pygamer")]
+const MAX_DRIFT_PPM: i64 = 50_000;
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Awaits [`DELAYS`] delays of the monotonic one after another, timing each against the
+    /// stopwatch, then shows the latency of the wakeups and the drift of the monotonic.
+    ///
+    /// The latency of a wakeup is how much longer than asked for its delay took by the
+    /// stopwatch, which includes rounding the delay up to whole ticks. The drift compares the
+    /// time the monotonic counted over all of the delays with the stopwatch, allowing
+    /// [`MAX_DRIFT_PPM`] besides a tick of the clock it is timed against and the spread of the
+    /// latencies.
+    ///
+    /// A stopwatch that counts the same clock as the monotonic would only show drift from missed
+    /// ticks, so the drift is then timed against the RTC, which the SysTick monotonic leaves free.
+    /// It is skipped if there is no such clock.
+    pub async fn run_mono_stats_test<S: Stopwatch>(&mut self, stopwatch: &mut S) {
+        let shares_clock = match Mono::backend() {
+            MonoBackend::SysTick => stopwatch.uses_core_clock(),
+            MonoBackend::Rtc => stopwatch.uses_rtc(),
+        };
+        let mut rtc = if shares_clock {
+            take_rtc_stopwatch()
+        } else {
+            None
+        };
+
+        let backend = Mono::backend().name();
+        let to_us =
+            |ticks: u32, rate_hz: u32| u64::from(ticks) * MICROS_PER_SEC / u64::from(rate_hz);
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "Timing {DELAYS} delays").unwrap();
+        writeln!(writer, "of {DELAY_MS} ms with {backend}...").unwrap();
+        writer.flush();
+
+        // Start on a wakeup, as each delay ends, so that the monotonic counts whole ticks
+        Mono::delay_ms(DELAY_MS).await;
+        let mut latency = Stats::new();
+        let mono_start = Mono::now();
+        let rtc_start = rtc.as_mut().map(|rtc| rtc.ticks());
+        let start = stopwatch.ticks();
+        let mut last = start;
+        for _ in 0..DELAYS {
+            Mono::delay_ms(DELAY_MS).await;
+            let now = stopwatch.ticks();
+            let took_us = to_us(now.wrapping_sub(last), stopwatch.rate_hz()) as i64;
+            latency.add((took_us - i64::from(DELAY_MS) * 1000) as i32);
+            last = now;
+        }
+        let mono_us = u64::from((Mono::now() - mono_start).to_micros()) as i64;
+        let reference_us = match rtc.as_mut().zip(rtc_start) {
+            Some((rtc, start)) => Some(to_us(rtc.ticks().wrapping_sub(start), rtc.rate_hz())),
+            None if shares_clock => None,
+            None => Some(to_us(last.wrapping_sub(start), stopwatch.rate_hz())),
+        }
+        .map(|us| us as i64);
+        // Positive when the monotonic runs fast
+        let drift_ppm = reference_us.map(|us| (mono_us - us) * MICROS_PER_SEC as i64 / us);
+
+        let min_latency_us = latency.min().unwrap();
+        let max_latency_us = latency.max().unwrap();
+        let mean_latency_us = latency.mean().unwrap();
+        let std_dev_us = latency.std_dev().unwrap();
+        // The timing is only as fine as a tick of the clock it is against, and the wakeups that
+        // start and end it may be late by different amounts
+        let reference_hz = rtc
+            .as_ref()
+            .map_or(stopwatch.rate_hz(), |rtc| rtc.rate_hz());
+        let resolution_us = MICROS_PER_SEC.div_ceil(u64::from(reference_hz)) as i64
+            + i64::from(max_latency_us - min_latency_us);
+        let max_drift_ppm =
+            reference_us.map(|us| MAX_DRIFT_PPM + resolution_us * MICROS_PER_SEC as i64 / us);
+        let drift = drift_ppm.zip(max_drift_ppm);
+        emit_results(|r| {
+            r.value("backend", &backend)?;
+            r.value("latency_min_us", &min_latency_us)?;
+            r.value("latency_mean_us", &mean_latency_us)?;
+            r.value("latency_max_us", &max_latency_us)?;
+            r.value("latency_std_dev_us", &std_dev_us)?;
+            match drift {
+                Some((drift_ppm, max_drift_ppm)) => {
+                    r.value("drift_ppm", &drift_ppm)?;
+                    r.value("drift_max_ppm", &max_drift_ppm)
+                }
+                None => r.case(
+                    "drift",
+                    Outcome::Skip,
+                    Some(&"no clock apart from that of the monotonic"),
+                ),
+            }
+        });
+
+        // A delay may only end early by the rounding of where it started within a tick
+        let tick_us = (MICROS_PER_SEC / u64::from(Mono::backend().tick_hz())) as i32;
+        check_range!(self, min_latency_us, -tick_us..=tick_us + LATENCY_SLACK_US);
+        check_range!(self, max_latency_us, -tick_us..=tick_us + LATENCY_SLACK_US);
+        if let Some((drift_ppm, max_drift_ppm)) = drift {
+            check_range!(self, drift_ppm, -max_drift_ppm..=max_drift_ppm);
+        }
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "{backend}, {DELAYS}x {DELAY_MS} ms:").unwrap();
+        writeln!(writer, "Latency in us:").unwrap();
+        writeln!(writer, " min {min_latency_us}, max {max_latency_us}").unwrap();
+        writeln!(writer, " mean {mean_latency_us}, sd {std_dev_us}").unwrap();
+        match drift.zip(reference_us) {
+            Some(((drift_ppm, max_drift_ppm), reference_us)) => {
+                writeln!(writer, "Drift: {drift_ppm} ppm").unwrap();
+                writeln!(writer, " limit {max_drift_ppm} ppm").unwrap();
+                writeln!(writer, " over {} ms", reference_us / 1000).unwrap();
+            }
+            _ => writeln!(writer, "Drift: skipped").unwrap(),
+        }
+        let _ = self.wait_for_button();
+    }
+}
@@ -1,18 +1,38 @@
 use crate::{
-    Input,
+    Input, check, check_eq, check_range,
+    checks::CheckValue,
     display::{Display, DisplayWriter},
+    results::{Outcome, emit_results},
+    retained::Retained,
+    rtc::{
+        AlarmMask, Datetime, RtcAlarm, RtcBackup, RtcClock, RtcCompare, RtcCount16, RtcCount32,
+        RtcFlag, RtcInterrupt,
+    },
     screens::ScreensGen,
 };
-use atsamd_hal::{
-    ehal::delay::DelayNs,
-    prelude::_embedded_hal_timer_CountDown as Countdown,
-    prelude::*,
-    rtc::{ClockMode, Count32Mode, Datetime, Rtc},
-};
 use core::fmt::Write;
+use fugit::ExtU32;
 use nb::block;
 
 const DELAY_SECS: u32 = 3;
+/// Most samples that are gathered for any RTC mode.
+const MAX_SAMPLES: usize = 8;
+/// How many cycles of the RTC clock reading the count may take, as it has to be synchronized
+/// from the RTC clock domain. The count can move on by this much between samples.
+const READ_MAX_CLOCKS: u32 = 6;
+/// What the count is set to before sampling it.
+const SET_COUNT: u32 = 1_000;
+/// The timeout that the prescaler is computed for.
+const PRESCALER_TIMEOUT_SECS: u32 = 3600;
+
+/// The prescaler that `reset_and_compute_prescaler` picks for a timeout: the smallest power of
+/// two, up to 1024, for which the timeout fits in 16 bits of counts.
+fn expected_prescaler(rtc_hz: u32, timeout_secs: u32) -> u32 {
+    let ticks = u64::from(timeout_secs) * u64::from(rtc_hz);
+    (ticks / u64::from(u16::MAX) + 1)
+        .next_power_of_two()
+        .min(1024) as u32
+}
 
 #[derive(Clone, Copy, Default, PartialEq, Eq)]
 struct FormattedDatetime(pub Datetime);
@@ -26,7 +46,327 @@ impl core::fmt::Debug for FormattedDatetime {
     }
 }
 
-trait RtcExt: Sized {
+impl CheckValue for FormattedDatetime {
+    fn fmt_value(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+const fn at(year: u8, month: u8, day: u8, hours: u8, minutes: u8, seconds: u8) -> Datetime {
+    Datetime {
+        seconds,
+        minutes,
+        hours,
+        day,
+        month,
+        year,
+    }
+}
+
+/// A second of the calendar that rolls over more than the seconds.
+struct Rollover {
+    name: &'static str,
+    from: Datetime,
+    /// The time a second after `from`.
+    to: Datetime,
+}
+
+/// Sets `from` to a second before midnight of the day, and `to` to midnight of the next one.
+const fn midnight(name: &'static str, from: (u8, u8, u8), to: (u8, u8, u8)) -> Rollover {
+    Rollover {
+        name,
+        from: at(from.0, from.1, from.2, 23, 59, 59),
+        to: at(to.0, to.1, to.2, 0, 0, 0),
+    }
+}
+
+/// Calendar edge cases, with the dates as (year, month, day).
+///
+/// The six bit year only reaches 2063, so the only century the hardware sees is 2000, which is
+/// a leap year like every other year divisible by four. Rather than rolling over into 2100, the
+/// year wraps from 63 back to 0.
+const ROLLOVERS: [Rollover; 19] = [
+    Rollover {
+        name: "next hour",
+        from: at(25, 6, 15, 10, 59, 59),
+        to: at(25, 6, 15, 11, 0, 0),
+    },
+    midnight("midnight", (25, 6, 15), (25, 6, 16)),
+    midnight("end of January", (25, 1, 31), (25, 2, 1)),
+    midnight("end of February", (25, 2, 28), (25, 3, 1)),
+    midnight("end of March", (25, 3, 31), (25, 4, 1)),
+    midnight("end of April", (25, 4, 30), (25, 5, 1)),
+    midnight("end of May", (25, 5, 31), (25, 6, 1)),
+    midnight("end of June", (25, 6, 30), (25, 7, 1)),
+    midnight("end of July", (25, 7, 31), (25, 8, 1)),
+    midnight("end of August", (25, 8, 31), (25, 9, 1)),
+    midnight("end of September", (25, 9, 30), (25, 10, 1)),
+    midnight("end of October", (25, 10, 31), (25, 11, 1)),
+    midnight("end of November", (25, 11, 30), (25, 12, 1)),
+    midnight("new year", (25, 12, 31), (26, 1, 1)),
+    midnight("leap day", (24, 2, 28), (24, 2, 29)),
+    midnight("after leap day", (24, 2, 29), (24, 3, 1)),
+    midnight("leap day in 2000", (0, 2, 28), (0, 2, 29)),
+    midnight("last leap day", (60, 2, 28), (60, 2, 29)),
+    midnight("year wraparound", (63, 12, 31), (0, 1, 1)),
+];
+
+/// Dates and times that do not exist, which [`RtcClock::try_set_time`] rejects.
+///
+/// The hardware takes any of them as is, so they are reported as skipped rather than checked.
+const INVALID: [(&str, Datetime); 10] = [
+    ("no leap day in 2025", at(25, 2, 29, 12, 0, 0)),
+    ("February 30", at(24, 2, 30, 12, 0, 0)),
+    ("April 31", at(25, 4, 31, 12, 0, 0)),
+    ("day 0", at(25, 6, 0, 12, 0, 0)),
+    ("month 0", at(25, 0, 1, 12, 0, 0)),
+    ("month 13", at(25, 13, 1, 12, 0, 0)),
+    ("hour 24", at(25, 6, 15, 24, 0, 0)),
+    ("minute 60", at(25, 6, 15, 12, 60, 0)),
+    ("second 60", at(25, 6, 15, 12, 0, 60)),
+    ("year 2099", at(99, 12, 31, 12, 0, 0)),
+];
+
+/// How far ahead of the count the compare is set, as a fraction of a second.
+const COMPARE_DIVISOR: u32 = 10;
+/// How many periodic interrupts are timed.
+const PERIODS: u32 = 4;
+
+/// The periodic interval closest to an eighth of a second, as far as the intervals reach.
+fn periodic_interval(rtc_hz: u32) -> u8 {
+    (rtc_hz / 8).ilog2().saturating_sub(3).min(7) as u8
+}
+
+/// How far ahead of the clock alarms are set.
+const ALARM_AHEAD_SECS: u8 = 2;
+/// The time the clock is set to before each alarm.
+const ALARM_START: Datetime = at(25, 6, 15, 10, 20, 0);
+/// When the alarms that match go off.
+const ALARM_TIME: Datetime = at(25, 6, 15, 10, 20, ALARM_AHEAD_SECS);
+
+/// An alarm set [`ALARM_AHEAD_SECS`] after [`ALARM_START`], or at some other time.
+struct AlarmCase {
+    name: &'static str,
+    mask: AlarmMask,
+    alarm: Datetime,
+    /// Whether the fields picked by the mask match [`ALARM_TIME`].
+    fires: bool,
+}
+
+/// Alarms at each mask level, those that go off differing from [`ALARM_TIME`] in every field
+/// the mask leaves out, and those that do not differing in the last field it compares.
+const ALARMS: [AlarmCase; 8] = [
+    AlarmCase {
+        name: "alarm on seconds",
+        mask: AlarmMask::Seconds,
+        alarm: at(26, 7, 16, 11, 21, ALARM_AHEAD_SECS),
+        fires: true,
+    },
+    AlarmCase {
+        name: "alarm on minutes",
+        mask: AlarmMask::Minutes,
+        alarm: at(26, 7, 16, 11, 20, ALARM_AHEAD_SECS),
+        fires: true,
+    },
+    AlarmCase {
+        name: "alarm on hours",
+        mask: AlarmMask::Hours,
+        alarm: at(26, 7, 16, 10, 20, ALARM_AHEAD_SECS),
+        fires: true,
+    },
+    AlarmCase {
+        name: "alarm on day",
+        mask: AlarmMask::Day,
+        alarm: at(26, 7, 15, 10, 20, ALARM_AHEAD_SECS),
+        fires: true,
+    },
+    AlarmCase {
+        name: "other second",
+        mask: AlarmMask::Seconds,
+        alarm: at(25, 6, 15, 10, 20, 30),
+        fires: false,
+    },
+    AlarmCase {
+        name: "other minute",
+        mask: AlarmMask::Minutes,
+        alarm: at(25, 6, 15, 10, 21, ALARM_AHEAD_SECS),
+        fires: false,
+    },
+    AlarmCase {
+        name: "other hour",
+        mask: AlarmMask::Hours,
+        alarm: at(25, 6, 15, 11, 20, ALARM_AHEAD_SECS),
+        fires: false,
+    },
+    AlarmCase {
+        name: "other day",
+        mask: AlarmMask::Day,
+        alarm: at(25, 6, 16, 10, 20, ALARM_AHEAD_SECS),
+        fires: false,
+    },
+];
+
+/// The flags of an alarm interrupt and the time it was seen at, if there was one.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct AlarmSeen(Option<(u8, Datetime)>);
+impl CheckValue for AlarmSeen {
+    fn fmt_value(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.0 {
+            Some((flags, time)) => write!(f, "flags {flags:#04X} at {:?}", FormattedDatetime(time)),
+            None => write!(f, "no alarm"),
+        }
+    }
+}
+
+/// Polls for the alarm until the clock has moved on `secs` times.
+fn wait_for_alarm<R: RtcAlarm>(rtc: &mut R, secs: u8) -> AlarmSeen {
+    let mut last = rtc.current_time();
+    let mut changes = 0;
+
+    while changes < secs {
+        if let Some(irq) = rtc.take_interrupts() {
+            return AlarmSeen(Some((irq.flags, rtc.current_time())));
+        }
+
+        let now = rtc.current_time();
+        if now != last {
+            changes += 1;
+            last = now;
+        }
+    }
+
+    AlarmSeen(None)
+}
+
+/// Polls for an interrupt until the count passes `until`, returning it and the count it was
+/// seen at.
+fn wait_for_count_interrupt<R: RtcCompare>(rtc: &mut R, until: u32) -> (Option<RtcInterrupt>, u32) {
+    loop {
+        let count = rtc.count32();
+        if let Some(irq) = rtc.take_interrupts() {
+            return (Some(irq), rtc.count32());
+        }
+        if count > until {
+            return (None, count);
+        }
+    }
+}
+
+/// The time the clock is set to just before the reset.
+const RESET_TIME: Datetime = at(25, 6, 15, 10, 20, 0);
+/// How many seconds the reset and the boot after it may take.
+const RESET_MAX_SECS: i64 = 3;
+/// How many clock samples are taken after the reset, to see that it still counts.
+const RESET_SAMPLES: usize = 3;
+/// What the backup registers are set to before the reset.
+const BACKUP_VALUES: [u32; 4] = [0x0123_4567, 0x89AB_CDEF, 0xDEAD_BEEF, 0x5A5A_A5A5];
+const BACKUP_NAMES: [&str; 4] = [
+    "backup register 0",
+    "backup register 1",
+    "backup register 2",
+    "backup register 3",
+];
+
+/// Seconds from `from` to `to`, counting days by the rules of the hardware.
+fn secs_between(from: Datetime, to: Datetime) -> i64 {
+    let secs = |time: Datetime| {
+        let year = i64::from(time.year);
+        // Every year divisible by four is a leap year, which year 0 is too
+        let days = year * 365
+            + (year + 3) / 4
+            + (1..time.month)
+                .map(|month| i64::from(Datetime { month, ..time }.days_in_month()))
+                .sum::<i64>()
+            + i64::from(time.day)
+            - 1;
+        ((days * 24 + i64::from(time.hours)) * 60 + i64::from(time.minutes)) * 60
+            + i64::from(time.seconds)
+    };
+    secs(to) - secs(from)
+}
+
+/// What the reset test knows from before the reset.
+#[derive(Clone, Copy)]
+pub struct RtcReset {
+    /// The time just before the reset.
+    before: Datetime,
+}
+impl RtcReset {
+    /// Sets the clock to [`RESET_TIME`] and the backup registers to [`BACKUP_VALUES`], and
+    /// captures a tamper timestamp, for the test to check once the reset is done.
+    pub fn prepare<R: RtcBackup>(rtc: &mut R) -> Self {
+        rtc.set_time(RESET_TIME);
+        for (n, value) in BACKUP_VALUES
+            .into_iter()
+            .enumerate()
+            .take(R::BACKUP_REGISTERS)
+        {
+            rtc.set_backup(n, value).unwrap();
+        }
+        if R::HAS_TAMPER {
+            // Clear any earlier event, so that the one after the reset is this one
+            rtc.take_tamper_timestamp();
+            rtc.trigger_tamper();
+        }
+
+        Self {
+            before: rtc.current_time(),
+        }
+    }
+
+    /// Takes what the reset test left before a software reset. If there is something, this boot
+    /// is the warm restart, and has to finish the test with
+    /// [`rtc_reset_test`](ScreensGen::rtc_reset_test) before the RTC is set up again.
+    pub fn take() -> Option<Self> {
+        RTC_RESET.take()
+    }
+}
+
+/// Where [`RtcReset`] is kept across the reset.
+#[cfg_attr(
+    any(feature = "metro", feature = "pygamer"),
+    unsafe(link_section = ".uninit.RTC_RESET")
+)]
+static RTC_RESET: Retained<RtcReset> = Retained::new();
+
+/// The period of the 16-bit counter, as a fraction of a second.
+const PERIOD_DIVISOR: u32 = 4;
+/// How many ticks before the overflow the samples of the 16-bit counter start.
+const OVERFLOW_LEAD: u16 = 3;
+/// The flags of the 16-bit counter, in the order they are set over a period.
+const FLAGS16: [RtcFlag; 3] = [RtcFlag::Match, RtcFlag::Match1, RtcFlag::Overflow];
+
+/// Polls the flags of the 16-bit counter until it overflows, or has counted two periods,
+/// returning the count each of [`FLAGS16`] was first seen at.
+fn wait_for_flags16<R: RtcCount16>(rtc: &mut R, period: u16) -> [Option<u16>; 3] {
+    let modulus = u32::from(period) + 1;
+    let mut seen = [None; FLAGS16.len()];
+    let mut last = rtc.count16();
+    let mut ticks = 0;
+
+    while seen[2].is_none() && ticks < 2 * modulus {
+        let flags = rtc.take_flags();
+        let count = rtc.count16();
+        for (flag, at) in FLAGS16.iter().zip(&mut seen) {
+            if flags & flag.mask() != 0 && at.is_none() {
+                *at = Some(count);
+            }
+        }
+
+        ticks += (u32::from(count) + modulus - u32::from(last)) % modulus;
+        last = count;
+    }
+
+    seen
+}
+
+// Markers to select the RTC mode being sampled, which keeps the blanket implementations below
+// from overlapping.
+struct Count32Samples;
+struct Count16Samples;
+struct ClockSamples;
+
+trait RtcExt<M>: Sized {
     type Count: Copy + Eq + Default + core::fmt::Debug;
     const NUM_SAMPLES: usize;
 
@@ -46,18 +386,15 @@ trait RtcExt: Sized {
         }
     }
 
-    fn get_counts(&self) -> [Self::Count; Self::NUM_SAMPLES] {
-        let mut counts = [Default::default(); Self::NUM_SAMPLES];
+    fn get_counts(&self, counts: &mut [Self::Count]) {
         counts[0] = self.count();
-        for i in 1..Self::NUM_SAMPLES {
-            counts[i] = self.wait_for_count_change();
+        for count in counts[1..].iter_mut() {
+            *count = self.wait_for_count_change();
         }
-
-        counts
     }
 }
 
-impl RtcExt for Rtc<Count32Mode> {
+impl<R: RtcCount32> RtcExt<Count32Samples> for R {
     type Count = u32;
     const NUM_SAMPLES: usize = 8;
 
@@ -66,7 +403,16 @@ impl RtcExt for Rtc<Count32Mode> {
     }
 }
 
-impl RtcExt for Rtc<ClockMode> {
+impl<R: RtcCount16> RtcExt<Count16Samples> for R {
+    type Count = u16;
+    const NUM_SAMPLES: usize = 8;
+
+    fn count(&self) -> Self::Count {
+        self.count16()
+    }
+}
+
+impl<R: RtcClock> RtcExt<ClockSamples> for R {
     type Count = FormattedDatetime;
     const NUM_SAMPLES: usize = 5;
 
@@ -79,53 +425,380 @@ impl<D: Display, I: Input> ScreensGen<D, I>
 where
     D::Error: core::fmt::Debug,
 {
-    fn show_counts<R: RtcExt>(&mut self, rtc: &mut R, msg: &str) -> DisplayWriter<'_, D>
-    where
-        [(); R::NUM_SAMPLES]:,
-    {
+    fn show_counts<M, R: RtcExt<M>>(&mut self, rtc: &mut R, msg: &str) -> DisplayWriter<'_, D> {
         let mut writer = self.new_screen();
         write!(writer, "Gathering counter samples...").unwrap();
         writer.flush();
-        let counts = rtc.get_counts();
+        let mut counts = [R::Count::default(); MAX_SAMPLES];
+        let counts = &mut counts[..R::NUM_SAMPLES];
+        rtc.get_counts(counts);
         let mut writer = self.new_screen();
         writeln!(writer, "{msg}:").unwrap();
-        for count in counts {
+        for count in counts.iter() {
             writeln!(writer, "{:?}", count).unwrap();
         }
 
         self.wait_for_button()
     }
 
-    fn date_rollover(
+    /// Shows count samples like [`show_counts`](Self::show_counts), after setting the count to
+    /// `set_count` if given, and checks that they count up from there a tick at a time.
+    ///
+    /// The samples are taken as the count changes, so each should be one step of the prescaled
+    /// clock past the last, or a few more if reading the count is slower than the prescaled clock.
+    fn check_counts<R: RtcCount32>(
         &mut self,
-        rtc: &mut Rtc<ClockMode>,
-        year: u8,
-        month: u8,
-        day: u8,
+        rtc: &mut R,
         msg: &str,
+        set_count: Option<u32>,
+        prescaler: u32,
     ) -> DisplayWriter<'_, D> {
-        rtc.set_time(Datetime {
-            seconds: 57,
-            minutes: 59,
-            hours: 23,
-            day,
-            month,
-            year,
-        });
-        self.show_counts(rtc, msg)
-    }
-
-    pub fn rtc_test(mut self, mut rtc: Rtc<Count32Mode>) -> !
+        let mut writer = self.new_screen();
+        write!(writer, "Gathering counter samples...").unwrap();
+        writer.flush();
+        // Only set the count once the screen is drawn, which takes many ticks
+        if let Some(count) = set_count {
+            rtc.set_count32(count);
+        }
+        let mut counts = [0; MAX_SAMPLES];
+        let counts = &mut counts[..<R as RtcExt<Count32Samples>>::NUM_SAMPLES];
+        RtcExt::<Count32Samples>::get_counts(rtc, counts);
+
+        let max_step = READ_MAX_CLOCKS.div_ceil(prescaler);
+        let steps = counts.windows(2).map(|pair| pair[1].wrapping_sub(pair[0]));
+        // A count that went backwards wraps around to a step of more than half the range
+        let increasing = steps.clone().all(|step| step < 1 << 31);
+        let largest_step = steps.max().unwrap();
+        check!(self, increasing);
+        check_range!(self, largest_step, 1..=max_step);
+        if let Some(set_count) = set_count {
+            let first = counts[0];
+            check_range!(self, first, set_count..=set_count + max_step);
+        }
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "{msg}:").unwrap();
+        writeln!(writer, "{}", counts[0]).unwrap();
+        for pair in counts.windows(2) {
+            writeln!(writer, "{} (+{})", pair[1], pair[1].wrapping_sub(pair[0])).unwrap();
+        }
+
+        self.wait_for_button()
+    }
+
+    /// Checks that each of the [`ROLLOVERS`] counts on to the expected time, showing a summary once
+    /// done.
+    ///
+    /// The [`INVALID`] times are reported as skipped, as only the software rejects them.
+    fn test_calendar<R: RtcClock>(&mut self, rtc: &mut R) -> DisplayWriter<'_, D> {
+        let mut failed = 0;
+
+        for (i, rollover) in ROLLOVERS.iter().enumerate() {
+            let mut writer = self.new_screen();
+            writeln!(writer, "Rollover {}/{}:", i + 1, ROLLOVERS.len()).unwrap();
+            writeln!(writer, "{}", rollover.name).unwrap();
+            writeln!(writer, "{:?}", FormattedDatetime(rollover.from)).unwrap();
+            writer.flush();
+
+            rtc.set_time(rollover.from);
+            let next = rtc.wait_for_count_change();
+            if !check_eq!(
+                self,
+                name = rollover.name,
+                next,
+                FormattedDatetime(rollover.to)
+            ) {
+                failed += 1;
+            }
+        }
+
+        for (name, _) in INVALID {
+            emit_results(|r| {
+                r.case(
+                    name,
+                    Outcome::Skip,
+                    Some(&"the hardware does not check dates"),
+                )
+            });
+        }
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "Calendar test:").unwrap();
+        writeln!(writer, "{} rollovers", ROLLOVERS.len()).unwrap();
+        writeln!(writer, "{} invalid dates skipped", INVALID.len()).unwrap();
+        writeln!(writer, "{failed} failed").unwrap();
+        self.wait_for_button()
+    }
+
+    /// Checks the compare and periodic interrupts against the count, which has to be running
+    /// without a prescaler.
+    fn test_count_interrupts<R: RtcCompare>(
+        &mut self,
+        rtc: &mut R,
+        rtc_hz: u32,
+    ) -> DisplayWriter<'_, D> {
+        let mut writer = self.new_screen();
+        write!(writer, "Waiting for RTC interrupts...").unwrap();
+        writer.flush();
+
+        let compare = rtc_hz / COMPARE_DIVISOR;
+        rtc.set_count32(0);
+        rtc.set_compare(compare);
+        let (irq, count) = wait_for_count_interrupt(rtc, 2 * compare);
+        rtc.disable_compare();
+
+        let compare_flags = irq.map_or(0, |irq| irq.flags);
+        check_eq!(self, compare_flags, RtcFlag::Match.mask());
+        check_range!(self, count, compare..=compare + READ_MAX_CLOCKS);
+
+        if !R::HAS_PERIODIC {
+            emit_results(|r| {
+                r.case(
+                    "periodic",
+                    Outcome::Skip,
+                    Some(&"no periodic interrupt on this chip"),
+                )
+            });
+            let mut writer = self.new_screen();
+            writeln!(writer, "RTC interrupts:").unwrap();
+            writeln!(writer, "Compare {compare}: at {count}").unwrap();
+            writeln!(writer, "No periodic interrupt").unwrap();
+            return self.wait_for_button();
+        }
+
+        let n = periodic_interval(rtc_hz);
+        let period = 1 << (n + 3);
+        rtc.set_count32(0);
+        rtc.enable_periodic(n);
+        // Time from the first interrupt, as the intervals do not start with the count
+        let (first, start) = wait_for_count_interrupt(rtc, 2 * period);
+        let mut seen = RtcInterrupt::default();
+        let mut end = start;
+        while seen.times < PERIODS {
+            let (irq, count) = wait_for_count_interrupt(rtc, end + 2 * period);
+            let Some(irq) = irq else {
+                break;
+            };
+            seen.flags |= irq.flags;
+            seen.times += irq.times;
+            end = count;
+        }
+        rtc.disable_periodic();
+
+        let periodic_flags = first.map_or(0, |irq| irq.flags) | seen.flags;
+        let periods = seen.times;
+        let elapsed = end - start;
+        let expected = PERIODS * period;
+        check_eq!(self, periodic_flags, RtcFlag::Periodic.mask());
+        check_eq!(self, periods, PERIODS);
+        check_range!(
+            self,
+            elapsed,
+            expected - READ_MAX_CLOCKS..=expected + READ_MAX_CLOCKS
+        );
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "RTC interrupts:").unwrap();
+        writeln!(writer, "Compare {compare}: at {count}").unwrap();
+        writeln!(writer, "Periodic {n}: every {period}").unwrap();
+        writeln!(writer, "{periods} in {elapsed} ticks").unwrap();
+        self.wait_for_button()
+    }
+
+    /// Checks that the 16-bit counter overflows at its period, and sets its compare and overflow
+    /// flags at the right counts.
+    fn test_count16<R: RtcCount16>(&mut self, rtc: &mut R, rtc_hz: u32) -> DisplayWriter<'_, D> {
+        let period = (rtc_hz / PERIOD_DIVISOR - 1) as u16;
+        let modulus = u32::from(period) + 1;
+        let step = |from: u16, to: u16| (u32::from(to) + modulus - u32::from(from)) % modulus;
+
+        let mut writer = self.new_screen();
+        write!(writer, "Gathering counter samples...").unwrap();
+        writer.flush();
+        rtc.set_period(period);
+        rtc.set_count16(period - OVERFLOW_LEAD);
+        let mut counts = [0; MAX_SAMPLES];
+        let counts = &mut counts[..<R as RtcExt<Count16Samples>>::NUM_SAMPLES];
+        RtcExt::<Count16Samples>::get_counts(rtc, counts);
+
+        let within_period = counts.iter().all(|&count| count <= period);
+        let overflowed = counts.windows(2).any(|pair| pair[1] < pair[0]);
+        let largest_step = counts
+            .windows(2)
+            .map(|pair| step(pair[0], pair[1]))
+            .max()
+            .unwrap();
+        check!(self, within_period);
+        check!(self, overflowed);
+        check_range!(self, largest_step, 1..=READ_MAX_CLOCKS);
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "16-bit counter to {period}:").unwrap();
+        writeln!(writer, "{}", counts[0]).unwrap();
+        for pair in counts.windows(2) {
+            writeln!(writer, "{} (+{})", pair[1], step(pair[0], pair[1])).unwrap();
+        }
+        let _ = self.wait_for_button();
+
+        let mut writer = self.new_screen();
+        write!(writer, "Waiting for the flags...").unwrap();
+        writer.flush();
+        let compares = [period / 4, period / 2];
+        for (n, &compare) in compares.iter().enumerate() {
+            rtc.set_compare16(n, compare);
+        }
+        rtc.set_count16(0);
+        rtc.take_flags();
+        let [compare0_at, compare1_at, overflow_at] = wait_for_flags16(rtc, period);
+
+        // A flag that never came shows as the first count past the range
+        let max_step = READ_MAX_CLOCKS as u16;
+        let compare0_at = compare0_at.unwrap_or(compares[0] + max_step + 1);
+        let compare1_at = compare1_at.unwrap_or(compares[1] + max_step + 1);
+        let overflow_at = overflow_at.unwrap_or(max_step + 1);
+        check_range!(self, compare0_at, compares[0]..=compares[0] + max_step);
+        check_range!(self, compare1_at, compares[1]..=compares[1] + max_step);
+        check_range!(self, overflow_at, 0..=max_step);
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "16-bit counter to {period}:").unwrap();
+        writeln!(writer, "Compare 0 {}: at {compare0_at}", compares[0]).unwrap();
+        writeln!(writer, "Compare 1 {}: at {compare1_at}", compares[1]).unwrap();
+        writeln!(writer, "Overflow: at {overflow_at}").unwrap();
+        self.wait_for_button()
+    }
+
+    /// Checks each of the [`ALARMS`], showing a summary once done.
+    fn test_alarms<R: RtcAlarm>(&mut self, rtc: &mut R) -> DisplayWriter<'_, D> {
+        let mut passed = [false; ALARMS.len()];
+
+        for (i, (case, passed)) in ALARMS.iter().zip(&mut passed).enumerate() {
+            let mut writer = self.new_screen();
+            writeln!(writer, "Alarm {}/{}:", i + 1, ALARMS.len()).unwrap();
+            writeln!(writer, "{}", case.name).unwrap();
+            writer.flush();
+
+            rtc.set_time(ALARM_START);
+            rtc.set_alarm(case.alarm, case.mask);
+            let seen = wait_for_alarm(rtc, ALARM_AHEAD_SECS + 1);
+            rtc.disable_alarm();
+
+            let expected = if case.fires {
+                AlarmSeen(Some((RtcFlag::Match.mask(), ALARM_TIME)))
+            } else {
+                AlarmSeen(None)
+            };
+            *passed = check_eq!(self, name = case.name, seen, expected);
+        }
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "RTC alarms:").unwrap();
+        for (case, passed) in ALARMS.iter().zip(passed) {
+            let verdict = if passed { "ok" } else { "FAIL" };
+            writeln!(writer, "{}: {verdict}", case.name).unwrap();
+        }
+        self.wait_for_button()
+    }
+
+    /// Checks what the RTC kept across the reset that `reset` was prepared for: the clock has to
+    /// have counted on from before it, and the backup registers and tamper timestamp have to be
+    /// as they were set.
+    ///
+    /// The clock is sampled as it changes, so this waits forever if it has stopped.
+    pub fn run_rtc_reset_test<R: RtcBackup>(&mut self, rtc: &mut R, reset: RtcReset) {
+        // Take the timestamp before a new event can replace it
+        let kept_tamper = rtc.take_tamper_timestamp();
+
+        let mut writer = self.new_screen();
+        write!(writer, "Gathering clock samples...").unwrap();
+        writer.flush();
+        let mut samples = [FormattedDatetime::default(); RESET_SAMPLES];
+        RtcExt::<ClockSamples>::get_counts(rtc, &mut samples);
+
+        let elapsed_secs = secs_between(reset.before, samples[0].0);
+        let counting = samples
+            .windows(2)
+            .all(|pair| secs_between(pair[0].0, pair[1].0) == 1);
+        check_range!(self, elapsed_secs, 0..=RESET_MAX_SECS);
+        check!(self, counting);
+
+        let mut backups_kept = true;
+        for n in 0..R::BACKUP_REGISTERS {
+            backups_kept &= check_eq!(
+                self,
+                name = BACKUP_NAMES[n],
+                rtc.backup(n),
+                Some(BACKUP_VALUES[n])
+            );
+        }
+        if R::BACKUP_REGISTERS == 0 {
+            emit_results(|r| r.case("backup registers", Outcome::Skip, Some(&"the RTC has none")));
+        }
+
+        let mut tamper_now = None;
+        if R::HAS_TAMPER {
+            let tamper_kept = kept_tamper.is_some_and(|time| {
+                secs_between(RESET_TIME, time) >= 0 && secs_between(time, reset.before) >= 0
+            });
+            check!(self, tamper_kept);
+
+            rtc.trigger_tamper();
+            let now = rtc.current_time();
+            let captured = rtc.take_tamper_timestamp();
+            let tamper_captured =
+                captured.is_some_and(|time| (0..=1).contains(&secs_between(time, now)));
+            check!(self, tamper_captured);
+            tamper_now = Some(tamper_captured);
+        } else {
+            emit_results(|r| {
+                r.case(
+                    "tamper timestamp",
+                    Outcome::Skip,
+                    Some(&"the RTC has no tamper input"),
+                )
+            });
+        }
+
+        let verdict = |passed| if passed { "ok" } else { "FAIL" };
+        let mut writer = self.new_screen();
+        writeln!(writer, "After the reset:").unwrap();
+        writeln!(writer, "before {:?}", FormattedDatetime(reset.before)).unwrap();
+        writeln!(writer, "after  {:?}", samples[0]).unwrap();
+        writeln!(
+            writer,
+            "{elapsed_secs} s later, counting: {}",
+            verdict(counting)
+        )
+        .unwrap();
+        if R::BACKUP_REGISTERS > 0 {
+            writeln!(writer, "Backup registers: {}", verdict(backups_kept)).unwrap();
+        }
+        if let Some(tamper_captured) = tamper_now {
+            match kept_tamper {
+                Some(time) => writeln!(writer, "tamper {:?}", FormattedDatetime(time)).unwrap(),
+                None => writeln!(writer, "No tamper timestamp").unwrap(),
+            }
+            writeln!(writer, "New tamper event: {}", verdict(tamper_captured)).unwrap();
+        }
+        let _ = self.wait_for_button();
+    }
+
+    /// Runs all of the RTC test screens with the RTC clocked at `rtc_hz`, returning the RTC in
+    /// clock mode once finished.
+    pub fn run_rtc_test<R: RtcCompare>(&mut self, mut rtc: R, rtc_hz: u32) -> R::ClockMode
     where
-        D::Error: core::fmt::Debug,
+        R::ClockMode: RtcAlarm,
     {
-        // Count 32 tests
-        let _ = self.show_counts(&mut rtc, "Basic counter test");
-        rtc.set_count32(1_000);
-        let _ = self.show_counts(&mut rtc, "Set counter test");
-        // This should set set the prescalar to 1024
-        rtc.reset_and_compute_prescaler(1u32.hours());
-        let mut writer = self.show_counts(&mut rtc, "Reset with prescalar");
+        // Count 32 tests, which start without a prescaler
+        let _ = self.check_counts(&mut rtc, "Basic counter test", None, 1);
+        let _ = self.check_counts(&mut rtc, "Set counter test", Some(SET_COUNT), 1);
+        let _ = self.test_count_interrupts(&mut rtc, rtc_hz);
+        let mut rtc16 = rtc.into_count16_mode();
+        let _ = self.test_count16(&mut rtc16, rtc_hz);
+        let mut rtc = rtc16.into_count32_mode();
+        rtc.reset_and_compute_prescaler(PRESCALER_TIMEOUT_SECS.secs());
+        let prescaler = expected_prescaler(rtc_hz, PRESCALER_TIMEOUT_SECS);
+        let mut writer = self.check_counts(&mut rtc, "Reset with prescalar", None, prescaler);
 
         // Periodic countdown timer test with the ehal 0.2 `Countdown` trait
         writeln!(
@@ -134,34 +807,59 @@ where
         )
         .unwrap();
         writer.flush();
-        Countdown::start(&mut rtc, DELAY_SECS.secs());
-        block!(Countdown::wait(&mut rtc)).unwrap();
+        rtc.start_countdown(DELAY_SECS.secs());
+        block!(rtc.wait_countdown()).unwrap();
         writeln!(writer, "Waiting another {DELAY_SECS} seconds...").unwrap();
         writer.flush();
-        block!(Countdown::wait(&mut rtc)).unwrap();
+        block!(rtc.wait_countdown()).unwrap();
         writeln!(writer, "One more delay of {DELAY_SECS} seconds...").unwrap();
         writer.flush();
-        block!(Countdown::wait(&mut rtc)).unwrap();
+        block!(rtc.wait_countdown()).unwrap();
         writeln!(writer, "Periodic `Countdown` test complete!").unwrap();
 
         // Delay with the ehal `DelayNs` trait
         let mut writer = self.wait_for_button();
         writeln!(writer, "Delaying for {DELAY_SECS} seconds...").unwrap();
         writer.flush();
-        rtc.delay_ms(DELAY_SECS as u32 * 1000);
+        rtc.delay_ms(DELAY_SECS * 1000);
         writeln!(writer, "Delaying another {DELAY_SECS} seconds...").unwrap();
         writer.flush();
-        rtc.delay_ms(DELAY_SECS as u32 * 1000);
+        rtc.delay_ms(DELAY_SECS * 1000);
         writeln!(writer, "`DelayNs` test complete!").unwrap();
         let _ = self.wait_for_button();
 
         // Now test clock mode
         let mut rtc = rtc.into_clock_mode();
         self.show_counts(&mut rtc, "Basic clock mode test");
-        self.date_rollover(&mut rtc, 24, 2, 28, "Happy leap day");
-        self.date_rollover(&mut rtc, 25, 2, 28, "No leap day here");
-        self.date_rollover(&mut rtc, 29, 12, 31, "Happy new year");
+        self.test_calendar(&mut rtc);
+        self.test_alarms(&mut rtc);
+        rtc
+    }
+
+    /// Runs all of the RTC test screens, then resets to check what the RTC keeps across it.
+    ///
+    /// The program has to check for [`RtcReset::take`] on boot, to finish the test with
+    /// [`rtc_reset_test`](Self::rtc_reset_test).
+    pub fn rtc_test<R: RtcCompare>(mut self, rtc: R, rtc_hz: u32) -> !
+    where
+        R::ClockMode: RtcAlarm + RtcBackup,
+    {
+        self.start_test("rtc");
+        let mut rtc = self.run_rtc_test(rtc, rtc_hz);
+        let mut writer = self.end_test();
+        writeln!(writer, "Press button to reset, and").unwrap();
+        writeln!(writer, "check the RTC across it.").unwrap();
+        writer.flush();
+        let _ = self.wait_for_button();
+
+        RTC_RESET.store(RtcReset::prepare(&mut rtc));
+        cortex_m::peripheral::SCB::sys_reset();
+    }
 
+    /// Finishes the RTC test after the reset, with the RTC as the reset left it.
+    pub fn rtc_reset_test<R: RtcBackup>(mut self, mut rtc: R, reset: RtcReset) -> ! {
+        self.start_test("rtc_reset");
+        self.run_rtc_reset_test(&mut rtc, reset);
         self.test_complete();
     }
 }
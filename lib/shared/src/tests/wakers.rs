@@ -0,0 +1,124 @@
+#[cfg(all(
+    any(feature = "metro", feature = "pygamer"),
+    not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+))]
+use crate::rtc::{RtcCompare, RtcFlag, next_interrupt};
+use crate::{
+    Input, check, check_eq,
+    display::Display,
+    executor::{WakeStats, block_on_with_stats},
+    screens::ScreensGen,
+};
+#[cfg(all(feature = "dma", any(feature = "metro", feature = "pygamer")))]
+use atsamd_hal::dmac::{
+    Ch0, Channel, ReadyFuture,
+    dma_controller::{TriggerAction, TriggerSource},
+};
+use core::fmt::Write;
+
+/// The delays that the async timer is checked with, by the name of each case.
+const TIMER_DELAYS_MS: [(&str, u32); 3] = [
+    ("timer delay 1ms", 1),
+    ("timer delay 10ms", 10),
+    ("timer delay 100ms", 100),
+];
+
+/// A timer with async delays, for [`check_timer_wakeups`](ScreensGen::check_timer_wakeups).
+pub trait AsyncTimer {
+    fn delay_ms(&mut self, ms: u32) -> impl Future<Output = ()>;
+}
+
+#[cfg(all(
+    any(feature = "metro", feature = "pygamer"),
+    not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+))]
+mod hal_timer {
+    use super::AsyncTimer;
+    use atsamd_hal::{pac, timer::TimerFuture};
+    use fugit::ExtU32;
+
+    /// TC5, which the programs that run the wakers test bind the interrupt of.
+    impl AsyncTimer for TimerFuture<pac::Tc5> {
+        fn delay_ms(&mut self, ms: u32) -> impl Future<Output = ()> {
+            self.delay(ms.millis())
+        }
+    }
+}
+
+impl<D: Display, I: Input> ScreensGen<D, I>
+where
+    D::Error: core::fmt::Debug,
+{
+    /// Blocks on a future of an async driver, checking that the driver woke it when it was ready
+    /// rather than leaving it to some other interrupt.
+    pub fn check_wakeups<F: Future>(&mut self, name: &str, future: F) -> (F::Output, WakeStats) {
+        let (output, stats) = block_on_with_stats(future);
+
+        let mut writer = self.new_screen();
+        writeln!(writer, "`{name}`: {stats}").unwrap();
+        writer.flush();
+
+        check_eq!(self, stats.missed, 0);
+        check!(self, stats.wakes > 0);
+        (output, stats)
+    }
+
+    /// Checks that the timer wakes the executor when each of a few delays is over.
+    pub fn check_timer_wakeups<T: AsyncTimer>(&mut self, timer: &mut T) {
+        for (name, ms) in TIMER_DELAYS_MS {
+            self.check_wakeups(name, timer.delay_ms(ms));
+        }
+    }
+
+    /// Checks that the async timer and the RTC interrupt wake the executor, with the RTC
+    /// clocked at `rtc_hz`. The program binds the interrupts of both.
+    #[cfg(all(
+        any(feature = "metro", feature = "pygamer"),
+        not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+    ))]
+    pub fn run_wakers_test<T: AsyncTimer, R: RtcCompare>(
+        &mut self,
+        timer: &mut T,
+        rtc: &mut R,
+        rtc_hz: u32,
+    ) {
+        self.check_timer_wakeups(timer);
+        self.check_rtc_wakeups(rtc, rtc_hz);
+    }
+
+    /// Waits for a compare about 10 ms ahead with the future of the RTC interrupt handler, which
+    /// has to be bound with [`bind_rtc_interrupt!`](crate::bind_rtc_interrupt).
+    #[cfg(all(
+        any(feature = "metro", feature = "pygamer"),
+        not(any(feature = "rtic-metro", feature = "rtic-pygamer"))
+    ))]
+    pub fn check_rtc_wakeups<R: RtcCompare>(&mut self, rtc: &mut R, rtc_hz: u32) {
+        rtc.take_interrupts();
+        rtc.set_compare(rtc.count32().wrapping_add(rtc_hz.div_ceil(100)));
+        let (irq, _) = self.check_wakeups("RTC compare", next_interrupt());
+        rtc.disable_compare();
+
+        check_eq!(self, irq.flags, RtcFlag::Match.mask());
+    }
+
+    /// Copies `source` into `dest` with a memory-to-memory transfer of the async DMAC, checking
+    /// that the DMAC interrupt, which the program binds, wakes the executor once it is done.
+    #[cfg(all(feature = "dma", any(feature = "metro", feature = "pygamer")))]
+    pub fn check_dmac_wakeups<const N: usize>(
+        &mut self,
+        channel: &mut Channel<Ch0, ReadyFuture>,
+        source: &mut [u8; N],
+        dest: &mut [u8; N],
+    ) {
+        let transfer = channel.transfer_future(
+            &mut *source,
+            &mut *dest,
+            TriggerSource::Disable,
+            TriggerAction::Block,
+        );
+        let (result, _) = self.check_wakeups("DMAC transfer", transfer);
+
+        check!(self, result.is_ok());
+        check_eq!(self, dest[..], source[..]);
+    }
+}
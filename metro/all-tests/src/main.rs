@@ -0,0 +1,125 @@
+//! Program to pick any of the tests from a menu, so that switching tests does not need reflashing.
+#![no_std]
+#![no_main]
+
+use hal::{
+    clock::{GenericClockController, Tc4Tc5Clock},
+    delay::Delay,
+    dmac::{DmaController, PriorityLevel},
+    rtc::{Count32Mode, Rtc},
+    timer::TimerCounter,
+};
+use shared_metro::{
+    prelude::*,
+    tests::{delay_ns::TcStopwatch, dmac, wakers},
+    usb,
+};
+
+/// The peripherals used by the tests. Those that a test consumes are taken while it runs and put
+/// back afterwards, so that every test can be run again.
+struct Context {
+    clocks: GenericClockController,
+    delay: Option<Delay>,
+    pm: pac::Pm,
+    timer: TimerCounter<pac::Tc3>,
+    /// TC4, which with TC5 times the delays, and otherwise leaves TC5 to the wakers test
+    tc4: Option<pac::Tc4>,
+    tc4_tc5_clock: Tc4Tc5Clock,
+    rtc: Option<Rtc<Count32Mode>>,
+    dmac: dmac::Resources,
+    async_timer: wakers::Timer,
+}
+
+const TESTS: &[HwTest<DisplayDriver, Buttons, Context>] = &[
+    HwTest::new("rtc", |screens, ctx| {
+        let rtc = ctx.rtc.take().unwrap();
+        let rtc = screens.run_rtc_test(rtc, RTC_CLOCK_RATE.to_Hz());
+        ctx.rtc = Some(rtc.into_count32_mode());
+    }),
+    HwTest::new("delay_ns", |screens, ctx| {
+        let tc4 = ctx.tc4.take().unwrap();
+        let mut stopwatch = TcStopwatch::new(tc4, &ctx.tc4_tc5_clock, &mut ctx.pm);
+        screens.run_delay_ns_test(
+            ctx.delay.as_mut().unwrap(),
+            &mut ctx.timer,
+            ctx.rtc.as_mut().unwrap(),
+            RTC_CLOCK_RATE.to_Hz(),
+            &mut stopwatch,
+        );
+        ctx.tc4 = Some(stopwatch.free());
+    }),
+    HwTest::new("executor", |screens, ctx| {
+        // Borrow SysTick from the delay for the time source
+        let cpu_hz = ctx.clocks.gclk0().freq().to_Hz();
+        let clock = SysTickClock::new(ctx.delay.take().unwrap().free(), cpu_hz);
+        screens.run_executor_test(&clock);
+        ctx.delay = Some(Delay::new(clock.free(), &mut ctx.clocks));
+    }),
+    HwTest::new("wakers", |screens, ctx| {
+        let rtc = ctx.rtc.as_mut().unwrap();
+        screens.run_wakers_test(&mut ctx.async_timer, rtc, RTC_CLOCK_RATE.to_Hz());
+        dmac::run_async(screens, &mut ctx.dmac);
+        screens.wait_for_button();
+    }),
+];
+
+hal::bind_interrupts!(struct Irqs {
+    TC5 => hal::timer::InterruptHandler<pac::Tc5>;
+    DMAC => hal::dmac::InterruptHandler;
+});
+
+// The RTC test waits for the RTC interrupt, and the executor test times its futures with the
+// SysTick clock
+bind_rtc_interrupt!();
+bind_systick!();
+
+#[entry]
+fn main() -> ! {
+    // The wakers test needs peripherals that the setup package does not keep
+    let peripherals = Peripherals::take().unwrap();
+    let mut pkg = SetupPackage::new(
+        unsafe { Peripherals::steal() },
+        CorePeripherals::take().unwrap(),
+    );
+
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb,
+        pkg.usb_dm,
+        pkg.usb_dp,
+        &mut pkg.clocks,
+        &mut pkg.pm,
+        &mut pkg.nvic,
+    );
+
+    let (rtc, _) = pkg.setup_rtc_clock().unwrap();
+
+    // Setup the timers, leaving TC4 for the stopwatch of the delay test
+    let gclk = pkg.clocks.gclk0();
+    let tcc2_tc3_clock = pkg.clocks.tcc2_tc3(&gclk).unwrap();
+    let timer = TimerCounter::tc3_(&tcc2_tc3_clock, peripherals.tc3, &mut pkg.pm);
+    let tc4_tc5_clock = pkg.clocks.tc4_tc5(&gclk).unwrap();
+    let async_timer =
+        TimerCounter::tc5_(&tc4_tc5_clock, peripherals.tc5, &mut pkg.pm).into_future(Irqs);
+
+    let channel = DmaController::init(peripherals.dmac, &mut pkg.pm)
+        .into_future(Irqs)
+        .split()
+        .0
+        .init(PriorityLevel::Lvl0);
+
+    let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.pm);
+    let mut ctx = Context {
+        clocks: pkg.clocks,
+        delay: Some(pkg.delay),
+        pm: pkg.pm,
+        timer,
+        tc4: Some(pkg.tc4),
+        tc4_tc5_clock,
+        rtc: Some(rtc),
+        dmac: dmac::Resources::new(channel),
+        async_timer,
+    };
+
+    Screens::new(pkg.display, pkg.buttons).test_menu(TESTS, &mut ctx);
+}
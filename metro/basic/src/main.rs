@@ -29,9 +29,9 @@ fn main() -> ! {
     writer.flush();
     buttons.button_c.wait_for_button();
 
-    writeln!(writer, "Now press any button to panic.").unwrap();
+    writeln!(writer, "Now hold any button to panic.").unwrap();
     writer.flush();
-    buttons.wait_for_button();
+    while !matches!(buttons.wait_for_event(), ButtonEvent::LongPress(_)) {}
 
     panic!("Test panic!");
 }
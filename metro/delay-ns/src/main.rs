@@ -5,24 +5,45 @@
 #![no_main]
 
 use hal::{rtc::Rtc, timer::TimerCounter};
-use shared_metro::prelude::*;
+use shared_metro::{prelude::*, tests::delay_ns::TcStopwatch, usb};
 
 #[entry]
 fn main() -> ! {
+    // The timer under test is TC3, which the setup package does not keep
+    let peripherals = Peripherals::take().unwrap();
     let mut pkg = SetupPackage::new(
-        Peripherals::take().unwrap(),
+        unsafe { Peripherals::steal() },
         CorePeripherals::take().unwrap(),
     );
+
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb,
+        pkg.usb_dm,
+        pkg.usb_dp,
+        &mut pkg.clocks,
+        &mut pkg.pm,
+        &mut pkg.nvic,
+    );
+
     let (rtc, _) = pkg.setup_rtc_clock().unwrap();
 
-    // Setup the timer
+    // Setup the timer, and time the delays with TC4 and TC5
     let gclk = pkg.clocks.gclk0();
+    let tcc2_tc3_clock = pkg.clocks.tcc2_tc3(&gclk).unwrap();
+    let timer = TimerCounter::tc3_(&tcc2_tc3_clock, peripherals.tc3, &mut pkg.pm);
     let tc4_tc5_clock = pkg.clocks.tc4_tc5(&gclk).unwrap();
-    let timer = TimerCounter::tc4_(&tc4_tc5_clock, pkg.tc4, &mut pkg.pm);
+    let stopwatch = TcStopwatch::new(pkg.tc4, &tc4_tc5_clock, &mut pkg.pm);
 
     // Setup the RTC
     let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.pm);
 
     // Run the test
-    Screens::new(pkg.display, pkg.buttons).delay_ns_test(pkg.delay, timer, rtc);
+    Screens::new(pkg.display, pkg.buttons).delay_ns_test(
+        pkg.delay,
+        timer,
+        rtc,
+        RTC_CLOCK_RATE.to_Hz(),
+        stopwatch,
+    );
 }
@@ -5,7 +5,10 @@
 #![no_main]
 
 use hal::rtc::Rtc;
-use shared_metro::prelude::*;
+use shared_metro::{prelude::*, usb};
+
+// The compare and alarm tests wait for the RTC interrupt
+bind_rtc_interrupt!();
 
 #[entry]
 fn main() -> ! {
@@ -14,8 +17,25 @@ fn main() -> ! {
         Peripherals::take().unwrap(),
         CorePeripherals::take().unwrap(),
     );
+
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb,
+        pkg.usb_dm,
+        pkg.usb_dp,
+        &mut pkg.clocks,
+        &mut pkg.pm,
+        &mut pkg.nvic,
+    );
+
     let (rtc, _) = pkg.setup_rtc_clock().unwrap();
-    let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.pm);
+    let screens = Screens::new(pkg.display, pkg.buttons);
+
+    // The test ends with a reset, after which the RTC has to be checked as it was left
+    if let Some(reset) = RtcReset::take() {
+        screens.rtc_reset_test(RetainedClock::new(rtc), reset);
+    }
 
-    Screens::new(pkg.display, pkg.buttons).rtc_test(rtc);
+    let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.pm);
+    screens.rtc_test(rtc, RTC_CLOCK_RATE.to_Hz());
 }
@@ -0,0 +1,53 @@
+//! Program to measure the wakeup latency and drift of the RTIC monotonic against a timer.
+//!
+//! Build it with each of the `systick`, `clock1k` and `clock32k` features to compare them, or with
+//! `mono-select` and a clock feature to pick the monotonic at boot.
+#![no_std]
+#![no_main]
+
+use shared_metro::{prelude::*, tests::delay_ns::TcStopwatch};
+
+#[rtic::app(device = pac, dispatchers = [TCC0])]
+mod app {
+    use super::*;
+
+    #[shared]
+    struct Shared {}
+
+    #[local]
+    struct Local {
+        screens: Screens,
+        stopwatch: TcStopwatch,
+    }
+
+    #[init]
+    fn init(cx: init::Context) -> (Shared, Local) {
+        let mut pkg = SetupPackage::new(cx.device, cx.core);
+        let (rtc, _) = pkg.setup_rtc_clock().unwrap();
+
+        // Time the monotonic with a timer, which is independent of the RTC. It counts the core
+        // clock as SysTick does, so the test times the drift of SysTick against the RTC instead
+        let gclk = pkg.clocks.gclk0();
+        let tc4_tc5_clock = pkg.clocks.tc4_tc5(&gclk).unwrap();
+        let stopwatch = TcStopwatch::new(pkg.tc4, &tc4_tc5_clock, &mut pkg.pm);
+
+        // Pick the monotonic if the program has both, then start it
+        let mut screens = Screens::new(pkg.display, pkg.buttons);
+        if cfg!(feature = "mono-select") {
+            screens.select_mono_backend();
+        }
+        Mono::general_start(pkg.delay.free(), rtc, pkg.sysclk);
+
+        stats_task::spawn().ok().unwrap();
+
+        (Shared {}, Local { screens, stopwatch })
+    }
+
+    #[task(priority = 1, local = [screens, stopwatch])]
+    async fn stats_task(cx: stats_task::Context) {
+        let screens = cx.local.screens;
+        screens.start_test("mono_stats");
+        screens.run_mono_stats_test(cx.local.stopwatch).await;
+        screens.show_test_complete();
+    }
+}
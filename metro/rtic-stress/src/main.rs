@@ -6,15 +6,19 @@
 #![feature(impl_trait_in_assoc_type)]
 
 use hal::prelude::*;
-use shared_metro::prelude::*;
+use shared_metro::prelude::{embedded_graphics::prelude::*, *};
 use shared_metro::tests::async_stress::{self, test_task};
 
 const BASE_PERIOD_MS: u32 = 1000;
 
+// NOTE: This does not work with more than 7 tasks besides the clock task, likely for memory
+// reasons, so the button task takes the place of one of them.
+#[stress_tasks(count = 6, task = test_task, period = BASE_PERIOD_MS)]
 #[rtic::app(device = pac, dispatchers = [TCC0])]
 mod app {
     use super::*;
     use bsp::RedLed;
+    use shared_metro::AsyncButtons;
 
     #[shared]
     struct Shared {
@@ -24,6 +28,7 @@ mod app {
     #[local]
     struct Local {
         red_led: RedLed,
+        buttons: AsyncButtons,
     }
 
     #[init]
@@ -31,16 +36,26 @@ mod app {
         let mut pkg = SetupPackage::new(cx.device, cx.core);
         let (rtc, _) = pkg.setup_rtc_clock().unwrap();
 
-        // Start the monotonic
-        Mono::general_start(pkg.delay.free(), rtc);
+        // Pick the monotonic if the program has both, then start it
+        if cfg!(feature = "mono-select") {
+            let mut screens = Screens::new(pkg.display, pkg.buttons);
+            screens.select_mono_backend();
+            (pkg.display, pkg.buttons) = screens.free();
+        }
+        Mono::general_start(pkg.delay.free(), rtc, pkg.sysclk);
 
         // Display selected monotonic and clock
         display_monotonic_info(&mut pkg.display);
 
+        // Wait for the buttons with interrupts rather than by polling
+        let buttons = pkg
+            .buttons
+            .into_async(&mut pkg.clocks, &mut pkg.pm, pkg.eic);
+
         // Spawn tasks
-        // NOTE: This does not work with more than 7 tasks, likely for memory reasons.
         clock_task::spawn().unwrap();
-        spawn_tasks();
+        button_task::spawn().unwrap();
+        spawn_stress_tasks();
 
         (
             Shared {
@@ -48,6 +63,7 @@ mod app {
             },
             Local {
                 red_led: pkg.red_led,
+                buttons,
             },
         )
     }
@@ -65,49 +81,8 @@ mod app {
         async_stress::clock_task(cx.shared.display).await
     }
 
-    #[inline]
-    fn spawn_tasks() {
-        task_1::spawn().ok().unwrap();
-        task_2::spawn().ok().unwrap();
-        task_3::spawn().ok().unwrap();
-        task_4::spawn().ok().unwrap();
-        task_5::spawn().ok().unwrap();
-        task_6::spawn().ok().unwrap();
-        task_7::spawn().ok().unwrap();
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_1(cx: task_1::Context) {
-        test_task(cx.shared.display, 0, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_2(cx: task_2::Context) {
-        test_task(cx.shared.display, 1, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_3(cx: task_3::Context) {
-        test_task(cx.shared.display, 2, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_4(cx: task_4::Context) {
-        test_task(cx.shared.display, 3, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_5(cx: task_5::Context) {
-        test_task(cx.shared.display, 4, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_6(cx: task_6::Context) {
-        test_task(cx.shared.display, 5, BASE_PERIOD_MS).await
-    }
-
-    #[task(priority = 1, shared=[display])]
-    async fn task_7(cx: task_7::Context) {
-        test_task(cx.shared.display, 6, BASE_PERIOD_MS).await
+    #[task(priority = 1, shared=[display], local=[buttons])]
+    async fn button_task(cx: button_task::Context) {
+        async_stress::button_task(cx.shared.display, cx.local.buttons, Point::new(0, 58)).await
     }
 }
@@ -5,11 +5,11 @@
 use core::str;
 
 use atsamd_usb_serial::heapless::String;
-use atsamd_usb_serial::prelude::*;
 
 use hal::prelude::*;
 use hal::rtc;
 use shared_metro::prelude::*;
+use shared_metro::usb;
 
 #[entry]
 fn main() -> ! {
@@ -20,23 +20,14 @@ fn main() -> ! {
     );
 
     let mut rtc = rtc::Rtc::clock_mode(pkg.setup_rtc_clock().unwrap().0, 1024.Hz(), &mut pkg.pm);
-    let mut usb_serial: UsbSerial = UsbSerial::new(
+    let mut usb_serial = usb::usb_serial(
+        pkg.usb,
+        pkg.usb_dm,
+        pkg.usb_dp,
+        &mut pkg.clocks,
+        &mut pkg.pm,
         &mut pkg.nvic,
-        bsp::usb_allocator(
-            pkg.usb,
-            &mut pkg.clocks,
-            &mut pkg.pm,
-            pkg.usb_dm,
-            pkg.usb_dp,
-        ),
-        StringDescriptors::new(LangID::EN)
-            .manufacturer("Fake company")
-            .product("Serial port")
-            .serial_number("TEST"),
-        UsbVidPid(0x16c0, 0x27dd),
-        true,
-    )
-    .unwrap();
+    );
 
     let mut screens = Screens::new(pkg.display, pkg.buttons);
     let mut writer = screens.new_screen();
@@ -3,7 +3,7 @@
 #![no_main]
 
 use hal as atsamd_hal;
-use shared_pygamer::prelude::*;
+use shared_pygamer::{prelude::*, tests};
 
 #[entry]
 fn main() -> ! {
@@ -28,29 +28,19 @@ fn main() -> ! {
     // Activate the RustCrypto backend
     let crypto = aes.activate_rustcrypto_backend();
 
-    // Set up key and data block
+    // Set up key
     let key = GenericArray::from_slice(&[0u8; 16]);
-    let mut block = aes::Block::default();
 
     // Initialize cipher
     let cipher = crypto.into_128bit(key);
 
-    // This copies the entire block
-    let block_copy = block;
-
-    // Encrypt block in-place and verify that it is different
-    cipher.encrypt_block(&mut block);
-    assert_ne!(block, block_copy);
-
-    // Decrypt it back and verify that is the same as it was
-    cipher.decrypt_block(&mut block);
-    assert_eq!(block, block_copy);
-
-    // Just show that the test has completed
+    // Now we can show the results
     let pkg = SetupPackage::new(
         unsafe { Peripherals::steal() },
         CorePeripherals::take().unwrap(),
     );
-
-    Screens::new(pkg.display, pkg.buttons).test_complete();
+    let mut screens = Screens::new(pkg.display, pkg.buttons);
+    screens.start_test("aes");
+    tests::aes::run(&mut screens, &cipher);
+    screens.test_complete();
 }
@@ -0,0 +1,148 @@
+//! Program to pick any of the tests from a menu, so that switching tests does not need reflashing.
+#![no_std]
+#![no_main]
+
+use hal::{
+    aes::{Aes, Aes128, GenericArray},
+    clock::GenericClockController,
+    delay::Delay,
+    dsu::Dsu,
+    rtc::{Count32Mode, Rtc},
+    timer::TimerCounter,
+};
+use shared_pygamer::{
+    prelude::*,
+    tests::{self, delay_ns::DwtStopwatch, dmac, wakers},
+    usb,
+};
+
+/// The peripherals used by the tests. Those that a test consumes are taken while it runs and put
+/// back afterwards, so that every test can be run again.
+struct Context {
+    clocks: GenericClockController,
+    delay: Option<Delay>,
+    timer: TimerCounter<pac::Tc4>,
+    rtc: Option<Rtc<Count32Mode>>,
+    stopwatch: DwtStopwatch,
+    dsu: Dsu,
+    cipher: Aes128,
+    dmac: Option<dmac::Resources>,
+    async_timer: wakers::Timer,
+}
+
+const TESTS: &[HwTest<DisplayDriver, Buttons, Context>] = &[
+    HwTest::new("rtc", |screens, ctx| {
+        let rtc = ctx.rtc.take().unwrap();
+        let rtc = screens.run_rtc_test(rtc, RTC_CLOCK_RATE.to_Hz());
+        ctx.rtc = Some(rtc.into_count32_mode());
+    }),
+    HwTest::new("delay_ns", |screens, ctx| {
+        screens.run_delay_ns_test(
+            ctx.delay.as_mut().unwrap(),
+            &mut ctx.timer,
+            ctx.rtc.as_mut().unwrap(),
+            RTC_CLOCK_RATE.to_Hz(),
+            &mut ctx.stopwatch,
+        );
+    }),
+    HwTest::new("executor", |screens, ctx| {
+        // Borrow SysTick from the delay for the time source
+        let cpu_hz = ctx.clocks.gclk0().freq().to_Hz();
+        let clock = SysTickClock::new(ctx.delay.take().unwrap().free(), cpu_hz);
+        screens.run_executor_test(&clock);
+        ctx.delay = Some(Delay::new(clock.free(), &mut ctx.clocks));
+    }),
+    HwTest::new("dsu", |screens, ctx| tests::dsu::run(screens, &mut ctx.dsu)),
+    HwTest::new("aes", |screens, ctx| tests::aes::run(screens, &ctx.cipher)),
+    HwTest::new("dmac", |screens, ctx| {
+        let resources = ctx.dmac.take().unwrap();
+        ctx.dmac = Some(dmac::run(screens, resources));
+    }),
+    HwTest::new("wakers", |screens, ctx| {
+        let rtc = ctx.rtc.as_mut().unwrap();
+        screens.run_wakers_test(&mut ctx.async_timer, rtc, RTC_CLOCK_RATE.to_Hz());
+        let resources = ctx.dmac.take().unwrap();
+        ctx.dmac = Some(dmac::run_async(screens, resources, DmacIrqs));
+        screens.wait_for_button();
+    }),
+];
+
+hal::bind_interrupts!(struct Irqs {
+    TC5 => hal::timer::InterruptHandler<pac::Tc5>;
+});
+
+hal::bind_multiple_interrupts!(struct DmacIrqs {
+    DMAC: [DMAC_0, DMAC_1, DMAC_2, DMAC_OTHER] => hal::dmac::InterruptHandler;
+});
+
+// The RTC test waits for the RTC interrupt, and the executor test times its futures with the
+// SysTick clock
+bind_rtc_interrupt!();
+bind_systick!();
+
+#[entry]
+fn main() -> ! {
+    // The DSU, AES and DMAC drivers need the clock v2 API, so set them up first
+    let mut peripherals = Peripherals::take().unwrap();
+    let (mut buses, clocks, tokens) = hal::clock::v2::clock_system_at_reset(
+        peripherals.oscctrl,
+        peripherals.osc32kctrl,
+        peripherals.gclk,
+        peripherals.mclk,
+        &mut peripherals.nvmctrl,
+    );
+    let dsu = Dsu::new(
+        peripherals.dsu,
+        clocks.ahbs.dsu,
+        clocks.apbs.dsu,
+        &mut peripherals.pac,
+    )
+    .unwrap();
+    let apb_clk = buses.apb.enable(tokens.apbs.aes);
+    let cipher = Aes::new(peripherals.aes, apb_clk)
+        .activate_rustcrypto_backend()
+        .into_128bit(GenericArray::from_slice(&[0u8; 16]));
+    let dmac = dmac::Resources::new(peripherals.dmac, clocks.ahbs.dmac);
+
+    // Everything else uses clock v1 through the setup package
+    let mut pkg = SetupPackage::new(
+        unsafe { Peripherals::steal() },
+        CorePeripherals::take().unwrap(),
+    );
+
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb_pins,
+        pkg.usb,
+        &mut pkg.clocks,
+        &mut pkg.mclk,
+        &mut pkg.nvic,
+    );
+
+    let rtc = pkg.setup_rtc_clock().unwrap();
+
+    // Setup the timer
+    let gclk = pkg.clocks.gclk0();
+    let tc4_tc5_clock = pkg.clocks.tc4_tc5(&gclk).unwrap();
+    let timer = TimerCounter::tc4_(&tc4_tc5_clock, pkg.tc4, &mut pkg.mclk);
+    let async_timer =
+        TimerCounter::tc5_(&tc4_tc5_clock, peripherals.tc5, &mut pkg.mclk).into_future(Irqs);
+
+    // Time delays with the cycle counter
+    let cpu_hz = gclk.freq().to_Hz();
+    let stopwatch = DwtStopwatch::new(pkg.dwt, &mut pkg.dcb, cpu_hz);
+
+    let mut ctx = Context {
+        clocks: pkg.clocks,
+        delay: Some(pkg.delay),
+        timer,
+        rtc: Some(Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.mclk)),
+        stopwatch,
+        dsu,
+        cipher,
+        dmac: Some(dmac),
+        async_timer,
+    };
+
+    Screens::new(pkg.display, pkg.buttons).test_menu(TESTS, &mut ctx);
+}
@@ -5,7 +5,7 @@
 #![no_main]
 
 use hal::{rtc::Rtc, timer::TimerCounter};
-use shared_pygamer::prelude::*;
+use shared_pygamer::{prelude::*, tests::delay_ns::DwtStopwatch, usb};
 
 #[entry]
 fn main() -> ! {
@@ -13,6 +13,16 @@ fn main() -> ! {
         Peripherals::take().unwrap(),
         CorePeripherals::take().unwrap(),
     );
+
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb_pins,
+        pkg.usb,
+        &mut pkg.clocks,
+        &mut pkg.mclk,
+        &mut pkg.nvic,
+    );
+
     let rtc = pkg.setup_rtc_clock().unwrap();
 
     // Setup the timer
@@ -23,6 +33,16 @@ fn main() -> ! {
     // Setup the RTC
     let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.mclk);
 
+    // Time the delays with the cycle counter
+    let cpu_hz = gclk.freq().to_Hz();
+    let stopwatch = DwtStopwatch::new(pkg.dwt, &mut pkg.dcb, cpu_hz);
+
     // Run the test
-    Screens::new(pkg.display, pkg.buttons).delay_ns_test(pkg.delay, timer, rtc);
+    Screens::new(pkg.display, pkg.buttons).delay_ns_test(
+        pkg.delay,
+        timer,
+        rtc,
+        RTC_CLOCK_RATE.to_Hz(),
+        stopwatch,
+    );
 }
@@ -6,7 +6,7 @@ use hal::dmac::{
     DmaController, PriorityLevel, Transfer,
     dma_controller::{TriggerAction, TriggerSource},
 };
-use shared_pygamer::prelude::*;
+use shared_pygamer::{prelude::*, tests::dmac as dmac_test};
 
 #[entry]
 fn main() -> ! {
@@ -53,40 +53,15 @@ fn main() -> ! {
         (dmac, ahb_clk)
     };
 
-    // Initialize DMA Channel 0
-    let chan0 = DmaController::new(dmac, ahb_clk)
-        .split()
-        .0
-        .init(PriorityLevel::Lvl0);
-
-    // Initialize buffers
-    const LENGTH: usize = 50;
-    let buf_src: &'static mut [u8; LENGTH] =
-        cortex_m::singleton!(: [u8; LENGTH] = [0xff; LENGTH]).unwrap();
-    let buf_dest: &'static mut [u8; LENGTH] =
-        cortex_m::singleton!(: [u8; LENGTH] = [0x00; LENGTH]).unwrap();
-
-    let mut xfer = Transfer::new(chan0, buf_src, buf_dest, false)
-        .unwrap()
-        .begin(TriggerSource::Disable, TriggerAction::Block);
-
-    // Transfer cycling example
-    {
-        const LENGTH: usize = 50;
-        let new_source: &'static mut [u8; LENGTH] =
-            cortex_m::singleton!(: [u8; LENGTH] = [0xff; LENGTH]).unwrap();
-        let new_destination: &'static mut [u8; LENGTH] =
-            cortex_m::singleton!(: [u8; LENGTH] = [0x00; LENGTH]).unwrap();
-
-        // Assume xfer is a `Busy` `Transfer`
-        let (_old_source, _old_dest) = xfer.recycle(new_source, new_destination).unwrap();
-    }
-
-    // Just show that the test has completed
+    // Now we can show the results
     let pkg = SetupPackage::new(
         unsafe { Peripherals::steal() },
         CorePeripherals::take().unwrap(),
     );
+    let mut screens = Screens::new(pkg.display, pkg.buttons);
 
-    Screens::new(pkg.display, pkg.buttons).test_complete();
+    // Transfer and transfer cycling examples
+    screens.start_test("dmac");
+    dmac_test::run(&mut screens, dmac_test::Resources::new(dmac, ahb_clk));
+    screens.test_complete();
 }
@@ -300,6 +300,26 @@ fn main() -> ! {
         CorePeripherals::take().unwrap(),
     );
 
+    // Dump the digests written back by the first pass, which take more than one screen
+    let hash = unsafe { core::ptr::read_volatile(&raw const *HASH) };
+    let mut text = PagedWriter::<512>::new();
+    for (name, region) in [
+        ("Region 0 (SHA1)", hash.region0),
+        ("Region 1 (SHA1)", hash.region1),
+        ("Region 2 (SHA224)", hash.region2),
+        ("Region 3 (SHA256)", hash.region3),
+    ] {
+        writeln!(text, "{name}:").unwrap();
+        for words in region.chunks(3) {
+            for word in words {
+                write!(text, " {word:08x}").unwrap();
+            }
+            writeln!(text).unwrap();
+        }
+    }
+
     // Show stuff
-    Screens::new(pkg.display, pkg.buttons).test_complete();
+    let mut screens = Screens::new(pkg.display, pkg.buttons);
+    let _ = screens.show_pages(&text);
+    screens.test_complete();
 }
@@ -5,7 +5,10 @@
 #![no_main]
 
 use hal::rtc::Rtc;
-use shared_pygamer::prelude::*;
+use shared_pygamer::{prelude::*, usb};
+
+// The compare and alarm tests wait for the RTC interrupt
+bind_rtc_interrupt!();
 
 #[entry]
 fn main() -> ! {
@@ -13,11 +16,24 @@ fn main() -> ! {
         Peripherals::take().unwrap(),
         CorePeripherals::take().unwrap(),
     );
-    let rtc = pkg.setup_rtc_clock().unwrap();
 
-    Screens::new(pkg.display, pkg.buttons).rtc_test(Rtc::count32_mode(
-        rtc,
-        RTC_CLOCK_RATE,
+    // Emit machine-readable results over USB serial
+    usb::init_results(
+        pkg.usb_pins,
+        pkg.usb,
+        &mut pkg.clocks,
         &mut pkg.mclk,
-    ));
+        &mut pkg.nvic,
+    );
+
+    let rtc = pkg.setup_rtc_clock().unwrap();
+    let screens = Screens::new(pkg.display, pkg.buttons);
+
+    // The test ends with a reset, after which the RTC has to be checked as it was left
+    if let Some(reset) = RtcReset::take() {
+        screens.rtc_reset_test(RetainedClock::new(rtc), reset);
+    }
+
+    let rtc = Rtc::count32_mode(rtc, RTC_CLOCK_RATE, &mut pkg.mclk);
+    screens.rtc_test(rtc, RTC_CLOCK_RATE.to_Hz());
 }
@@ -7,6 +7,7 @@ use embedded_graphics::prelude::*;
 use hal::prelude::*;
 use rtic::Mutex;
 use shared_pygamer::prelude::*;
+use shared_pygamer::tests::async_stress::button_task;
 
 const BASE_PERIOD_MS: u32 = 250;
 
@@ -52,6 +53,7 @@ mod app {
     #[local]
     struct Local {
         red_led: RedLed,
+        buttons: Buttons,
     }
 
     #[init]
@@ -60,7 +62,7 @@ mod app {
         let rtc = pkg.setup_rtc_clock().unwrap();
 
         // Start the monotonic
-        Mono::general_start(pkg.delay.free(), rtc);
+        Mono::general_start(pkg.delay.free(), rtc, pkg.sysclk);
 
         // Display selected monotonic and clock
         display_monotonic_info(&mut pkg.display);
@@ -69,6 +71,7 @@ mod app {
         test_2::spawn().ok().unwrap();
         test_3::spawn().ok().unwrap();
         test_4::spawn().ok().unwrap();
+        buttons::spawn().ok().unwrap();
 
         (
             Shared {
@@ -76,6 +79,7 @@ mod app {
             },
             Local {
                 red_led: pkg.red_led,
+                buttons: pkg.buttons,
             },
         )
     }
@@ -107,4 +111,9 @@ mod app {
     async fn test_4(cx: test_4::Context) {
         test_task(cx.shared.display, 4, BASE_PERIOD_MS * 4, Some(4)).await;
     }
+
+    #[task(priority = 1, shared=[display], local=[buttons])]
+    async fn buttons(cx: buttons::Context) {
+        button_task(cx.shared.display, cx.local.buttons, Point::new(10, 120)).await
+    }
 }
@@ -45,7 +45,7 @@ mod app {
         display_monotonic_info(&mut pkg.display);
 
         // Start the monotonic
-        Mono::general_start(pkg.delay.free(), rtc);
+        Mono::general_start(pkg.delay.free(), rtc, pkg.sysclk);
 
         // Show the count sequence
         let mut counts = [0; NUM_SAMPLES];
@@ -56,8 +56,14 @@ mod app {
             counts[i] = wait_for_count_change().ticks();
         }
 
-        let style = pkg.display.display_text_style(Point::zero());
-        write!(DisplayWriter::new(&mut pkg.display, style), "{counts:?}").unwrap();
+        // Scroll rather than truncate, leaving the bottom row for the monotonic info
+        let mut style = pkg.display.display_text_style(Point::zero());
+        style.char_box_size = style.char_box_size.map(|s| s - Size::new(0, 1));
+        let mut terminal = Terminal::new();
+        let mut writer = DisplayWriter::terminal(&mut pkg.display, style, &mut terminal);
+        for (i, count) in counts.iter().enumerate() {
+            writeln!(writer, "{i:2}: {count}").unwrap();
+        }
 
         (
             Shared {},